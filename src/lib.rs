@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 use std::cmp::Ordering::{self, *};
-use std::f64::consts::{E, LN_10, LOG2_10, PI};
+use std::collections::VecDeque;
+use std::f64::consts::{E, LN_10, LOG10_2, LOG2_10, PI};
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::iter::Sum;
 use std::num::ParseFloatError;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
 use std::str::FromStr;
 
 mod macros;
@@ -25,6 +27,11 @@ pub const EXP_LIMIT: f64 = 1.79e308;
 /// Tolerance which is used for f64 conversion to compensate for floating-point error.
 pub const ROUND_TOLERANCE: f64 = f64::EPSILON;
 
+/// Tolerance, relative to the magnitude of the result, within which `to_number` snaps to the nearest integer.
+/// This covers the few ulps of error from multiplying by a power of 10, without losing fractional digits
+/// that Display relies on.
+pub const NUMBER_ROUND_TOLERANCE: f64 = 1e-15;
+
 /// The smallest exponent that can appear in an f64, though not all mantissas are valid here.
 pub const NUMBER_EXP_MIN: i32 = -324;
 
@@ -86,6 +93,57 @@ pub fn to_fixed_num(num: f64, places: u32) -> f64 {
 	to_fixed(num, places).parse::<f64>().unwrap()
 }
 
+/// Formats the given number the same way as JavaScript's `Number.prototype.toString`.
+fn to_js_number_string(num: f64) -> String {
+	if f64::is_nan(num) {
+		return String::from("NaN");
+	} else if f64::is_infinite(num) {
+		return String::from(if num > 0.0 { "Infinity" } else { "-Infinity" });
+	} else if num == 0.0 {
+		return String::from("0");
+	}
+
+	let abs = num.abs();
+	if (1e-6..1e21).contains(&abs) {
+		num.to_string()
+	} else {
+		let string = format!("{:e}", num);
+		if string.contains("e-") {
+			string
+		} else {
+			string.replace('e', "e+")
+		}
+	}
+}
+
+/// The digits used for base-62 encoding.
+const BASE62_DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The short-scale names of the powers of 1000, starting from 1000^1.
+const ILLION_NAMES: &[&str] = &[
+	"thousand",
+	"million",
+	"billion",
+	"trillion",
+	"quadrillion",
+	"quintillion",
+	"sextillion",
+	"septillion",
+	"octillion",
+	"nonillion",
+	"decillion",
+	"undecillion",
+	"duodecillion",
+	"tredecillion",
+	"quattuordecillion",
+	"quindecillion",
+	"sexdecillion",
+	"septendecillion",
+	"octodecillion",
+	"novemdecillion",
+	"vigintillion",
+];
+
 /// Returns the power of 10 with the given exponent from the cache.
 fn power_of_10(power: i32) -> f64 {
 	CACHED_POWERS[(power - NUMBER_EXP_MIN) as usize]
@@ -108,7 +166,33 @@ pub fn from_mantissa_exponent(mantissa: f64, exponent: f64) -> Decimal {
 	decimal.normalize()
 }
 
+/// Creates a new instance of Decimal with the given mantissa and exponent with normalizing them,
+/// or returns an error if they are not finite or normalize to outside of the representable range.
+pub fn try_from_mantissa_exponent(mantissa: f64, exponent: f64) -> Result<Decimal, &'static str> {
+	if !f64::is_finite(mantissa) || !f64::is_finite(exponent) {
+		return Err("mantissa and exponent must be finite");
+	}
+
+	let decimal = from_mantissa_exponent(mantissa, exponent);
+	if decimal.exponent >= EXP_LIMIT {
+		Err("exponent overflows the exponent limit")
+	} else if decimal.exponent <= -EXP_LIMIT {
+		Err("exponent underflows the exponent limit")
+	} else {
+		Ok(decimal)
+	}
+}
+
 /// A struct representing a decimal number, which can reach a maximum of 1e1.79e308 instead of `f64`'s maximum of 1.79e308.
+///
+/// As with `f64`, any arithmetic operation with a NaN operand results in NaN.
+///
+/// By default, arithmetic results past the exponent limit are kept and treated as Infinity.
+/// With the `nan-on-overflow` feature, they are NaN instead, so that overflow can be told apart
+/// from a value which is legitimately at the limit.
+///
+/// With the `deterministic` feature, the result of each arithmetic operation is rounded to 15 significant digits,
+/// so that it is identical across platforms.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Decimal {
@@ -132,7 +216,7 @@ impl Display for Decimal {
 			return if let Some(places) = f.precision() {
 				write!(f, "{:.*}", places, self.to_number().to_string())
 			} else {
-				write!(f, "{}", self.to_number())
+				write!(f, "{}", self.standard_notation_string())
 			};
 		}
 
@@ -150,39 +234,7 @@ impl Add<Decimal> for Decimal {
 	type Output = Decimal;
 
 	fn add(self, decimal: Decimal) -> Decimal {
-		// Figure out which is bigger, shrink the mantissa of the smaller
-		// by the difference in exponents, add mantissas, normalize and return
-		// TODO: Optimizations and simplification may be possible, see https://github.com/Patashu/break_infinity.js/issues/8
-		if self.mantissa == 0.0 {
-			return decimal;
-		}
-
-		if decimal.mantissa == 0.0 {
-			return self;
-		}
-
-		let bigger_decimal;
-		let smaller_decimal;
-
-		if self.exponent >= decimal.exponent {
-			bigger_decimal = self;
-			smaller_decimal = decimal;
-		} else {
-			bigger_decimal = decimal;
-			smaller_decimal = self;
-		}
-
-		if bigger_decimal.exponent - smaller_decimal.exponent > MAX_SIGNIFICANT_DIGITS as f64 {
-			return bigger_decimal;
-		}
-
-		from_mantissa_exponent(
-			(1e14 * bigger_decimal.mantissa)
-				+ 1e14
-					* smaller_decimal.mantissa
-					* power_of_10((smaller_decimal.exponent - bigger_decimal.exponent) as i32),
-			bigger_decimal.exponent - 14.0,
-		)
+		self.add_unfinished(decimal).finish_arithmetic()
 	}
 }
 
@@ -271,7 +323,7 @@ impl Mul<Decimal> for Decimal {
 	type Output = Decimal;
 
 	fn mul(self, decimal: Decimal) -> Decimal {
-		from_mantissa_exponent(self.mantissa * decimal.mantissa, self.exponent + decimal.exponent)
+		self.mul_unfinished(decimal).finish_arithmetic()
 	}
 }
 
@@ -356,6 +408,76 @@ impl DivAssign<Decimal> for Decimal {
 	}
 }
 
+/// The remainder has the same sign as the dividend, as with `f64`.
+/// Use [Decimal::rem_euclid] for a remainder which is never negative.
+impl Rem<Decimal> for Decimal {
+	type Output = Decimal;
+
+	fn rem(self, decimal: Decimal) -> Decimal {
+		if self.is_nan() || decimal.is_nan() || decimal.mantissa == 0.0 {
+			return Decimal::NAN;
+		}
+
+		let quotient = self / decimal;
+		if quotient.exponent >= MAX_SIGNIFICANT_DIGITS as f64 {
+			// The remainder is lost to precision.
+			return Decimal::ZERO;
+		}
+
+		self - decimal * quotient.trunc()
+	}
+}
+
+impl Rem<&Decimal> for Decimal {
+	type Output = Decimal;
+
+	fn rem(self, decimal: &Decimal) -> Decimal {
+		self % *decimal
+	}
+}
+
+impl Rem<Decimal> for &Decimal {
+	type Output = Decimal;
+
+	fn rem(self, decimal: Decimal) -> Decimal {
+		*self % decimal
+	}
+}
+
+impl Rem<&Decimal> for &Decimal {
+	type Output = Decimal;
+
+	fn rem(self, decimal: &Decimal) -> Decimal {
+		*self % *decimal
+	}
+}
+
+impl Rem<f64> for Decimal {
+	type Output = Decimal;
+
+	fn rem(self, number: f64) -> Decimal {
+		self % Decimal::new(number)
+	}
+}
+
+impl RemAssign<&Decimal> for Decimal {
+	fn rem_assign(&mut self, rhs: &Decimal) {
+		*self = *self % rhs;
+	}
+}
+
+impl RemAssign<Decimal> for Decimal {
+	fn rem_assign(&mut self, rhs: Decimal) {
+		*self = *self % rhs;
+	}
+}
+
+impl RemAssign<f64> for Decimal {
+	fn rem_assign(&mut self, rhs: f64) {
+		*self = *self % rhs;
+	}
+}
+
 impl Neg for &Decimal {
 	type Output = Decimal;
 
@@ -373,6 +495,18 @@ impl Neg for Decimal {
 	}
 }
 
+impl Sum<Decimal> for Decimal {
+	fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+		iter.fold(Decimal::ZERO, |total, decimal| total + decimal)
+	}
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+	fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+		iter.fold(Decimal::ZERO, |total, decimal| total + decimal)
+	}
+}
+
 impl PartialOrd for Decimal {
 	fn partial_cmp(&self, decimal: &Self) -> Option<Ordering> {
 		/*
@@ -412,8 +546,8 @@ impl PartialOrd for Decimal {
 			|| (f64::is_infinite(decimal.mantissa) && decimal.mantissa.is_sign_positive())
 		{
 			Some(Less)
-		} else if (f64::is_infinite(self.mantissa) && self.mantissa.is_sign_negative())
-			|| (f64::is_infinite(decimal.mantissa) && decimal.mantissa.is_sign_positive())
+		} else if (f64::is_infinite(self.mantissa) && self.mantissa.is_sign_positive())
+			|| (f64::is_infinite(decimal.mantissa) && decimal.mantissa.is_sign_negative())
 		{
 			Some(Greater)
 		} else if self.mantissa == 0.0 {
@@ -462,11 +596,12 @@ impl PartialEq<Decimal> for Decimal {
 
 impl Eq for Decimal {}
 
+/// Parsing the string produced by [Display] yields the original Decimal, within a relative tolerance of 1e-12.
 impl FromStr for Decimal {
 	type Err = ParseFloatError;
 
 	fn from_str(string: &str) -> Result<Decimal, ParseFloatError> {
-		if let Some((mantissa, exponent)) = string.split_once('e') {
+		if let Some((mantissa, exponent)) = string.split_once(['e', 'E']) {
 			let decimal = Decimal {
 				mantissa: mantissa.parse()?,
 				exponent: exponent.parse()?,
@@ -481,12 +616,144 @@ impl FromStr for Decimal {
 	}
 }
 
+/// An error which can be returned when parsing a Decimal from a string of digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDecimalError {
+	/// The string contained no digits.
+	Empty,
+	/// The string contained a character that is not a valid digit.
+	InvalidDigit,
+	/// The string encoded a number too large to fit in a Decimal.
+	Overflow,
+	/// The token at the given index, counting from zero, is not a valid Decimal.
+	InvalidToken(usize),
+}
+
+impl Display for ParseDecimalError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseDecimalError::Empty => write!(f, "cannot parse decimal from empty string"),
+			ParseDecimalError::InvalidDigit => write!(f, "invalid digit found in string"),
+			ParseDecimalError::Overflow => write!(f, "number too large to fit in decimal"),
+			ParseDecimalError::InvalidToken(index) => write!(f, "invalid decimal found at token {}", index),
+		}
+	}
+}
+
+impl std::error::Error for ParseDecimalError {}
+
 impl Default for Decimal {
 	fn default() -> Self {
 		Decimal::ZERO
 	}
 }
 
+/// Conversion into a Decimal by reference, implemented for every type that Decimal can be created from.
+pub trait ToDecimal {
+	fn to_decimal(&self) -> Decimal;
+}
+
+impl ToDecimal for Decimal {
+	fn to_decimal(&self) -> Decimal {
+		*self
+	}
+}
+
+/// A wrapper which orders Decimals in reverse, so that a [BinaryHeap](std::collections::BinaryHeap) of it
+/// pops the smallest Decimal first. NaN is ordered as the smallest.
+///
+/// As Decimal only implements [PartialOrd], `std::cmp::Reverse<Decimal>` cannot be used for this.
+#[derive(Clone, Copy, Debug)]
+pub struct MinOrd(pub Decimal);
+
+impl PartialEq for MinOrd {
+	fn eq(&self, other: &MinOrd) -> bool {
+		self.cmp(other) == Equal
+	}
+}
+
+impl Eq for MinOrd {}
+
+impl PartialOrd for MinOrd {
+	fn partial_cmp(&self, other: &MinOrd) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for MinOrd {
+	fn cmp(&self, other: &MinOrd) -> Ordering {
+		other.0.total_cmp(&self.0)
+	}
+}
+
+/// An exponential moving average of Decimal samples, for smoothing values such as income rates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ema {
+	value: Option<Decimal>,
+	alpha: f64,
+}
+
+impl Ema {
+	/// Creates an empty moving average with the given smoothing factor, where higher values weight recent samples more.
+	pub fn new(alpha: f64) -> Ema {
+		Ema { value: None, alpha }
+	}
+
+	/// Adds a sample to the moving average. The first sample becomes the average.
+	pub fn update(&mut self, sample: &Decimal) {
+		self.value = Some(match self.value {
+			Some(value) => sample * Decimal::new(self.alpha) + value * Decimal::new(1.0 - self.alpha),
+			None => *sample,
+		});
+	}
+
+	/// Returns the current average, or zero if no samples have been added.
+	pub fn get(&self) -> Decimal {
+		self.value.unwrap_or(Decimal::ZERO)
+	}
+}
+
+/// A running geometric mean of Decimal samples, accumulated in log space so that it cannot overflow.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeometricMean {
+	// The exponents are summed apart from the log10 of the mantissas, so that their precision isn't lost.
+	sum_exponent: f64,
+	sum_log_mantissa: f64,
+	count: u64,
+}
+
+impl GeometricMean {
+	/// Creates an empty geometric mean.
+	pub fn new() -> GeometricMean {
+		GeometricMean::default()
+	}
+
+	/// Adds a sample to the geometric mean. Samples which are not positive have no logarithm, so they are skipped.
+	pub fn update(&mut self, sample: &Decimal) {
+		if sample.mantissa > 0.0 {
+			self.sum_exponent += sample.exponent;
+			self.sum_log_mantissa += sample.mantissa.log10();
+			self.count += 1;
+		}
+	}
+
+	/// Returns the current geometric mean, or zero if no samples have been added.
+	pub fn get(&self) -> Decimal {
+		if self.count == 0 {
+			return Decimal::ZERO;
+		}
+
+		let count = self.count as f64;
+		Decimal::pow10(self.sum_exponent / count) * Decimal::pow10(self.sum_log_mantissa / count)
+	}
+}
+
+impl From<&Decimal> for Decimal {
+	fn from(decimal: &Decimal) -> Decimal {
+		*decimal
+	}
+}
+
 // This allows converting virtually any number to a Decimal.
 impl_from!(i8);
 impl_from!(i16);
@@ -525,6 +792,30 @@ impl Decimal {
 		mantissa: -1.0,
 		exponent: 0.0,
 	};
+	pub const TEN: Decimal = Decimal {
+		mantissa: 1.0,
+		exponent: 1.0,
+	};
+	pub const HUNDRED: Decimal = Decimal {
+		mantissa: 1.0,
+		exponent: 2.0,
+	};
+	pub const THOUSAND: Decimal = Decimal {
+		mantissa: 1.0,
+		exponent: 3.0,
+	};
+	pub const MILLION: Decimal = Decimal {
+		mantissa: 1.0,
+		exponent: 6.0,
+	};
+	pub const BILLION: Decimal = Decimal {
+		mantissa: 1.0,
+		exponent: 9.0,
+	};
+	pub const TRILLION: Decimal = Decimal {
+		mantissa: 1.0,
+		exponent: 12.0,
+	};
 	pub const E: Decimal = Decimal {
 		mantissa: E,
 		exponent: 0.0,
@@ -535,7 +826,92 @@ impl Decimal {
 	};
 
 	/// Creates a new instance of Decimal with the given value.
+	///
+	/// The mantissa is rounded to 16 significant digits, so that values such as integers come out exactly.
 	pub fn new(value: f64) -> Decimal {
+		Decimal::new_with_rounding(value, 1e15)
+	}
+
+	/// Creates a new instance of Decimal with the given value, keeping all 17 significant digits of the mantissa.
+	///
+	/// This is more accurate than [Decimal::new], but floating-point error is no longer rounded away,
+	/// so integers may not come out exactly.
+	pub fn new_precise(value: f64) -> Decimal {
+		Decimal::new_with_rounding(value, 1e16)
+	}
+
+	/// Creates a new instance of Decimal with the given value multiplied by 10^`exp_shift`,
+	/// such as a value and a separately stored tier, normalizing only once.
+	pub fn from_f64_shifted(value: f64, exp_shift: f64) -> Decimal {
+		let decimal = Decimal::new(value);
+		if !value.is_finite() || value == 0.0 {
+			return decimal;
+		} else if exp_shift.fract() != 0.0 {
+			return from_mantissa_exponent(
+				decimal.mantissa * 10.0_f64.powf(exp_shift.fract()),
+				decimal.exponent + exp_shift.trunc(),
+			);
+		}
+
+		from_mantissa_exponent_no_normalize(decimal.mantissa, decimal.exponent + exp_shift)
+	}
+
+	/// Creates a new instance of Decimal with the value `mantissa * 2^exp2`, such as from a binary floating-point format.
+	/// The power of 2 is converted through its log10 when it would not fit in an f64.
+	pub fn from_base2(mantissa: f64, exp2: i32) -> Decimal {
+		if !mantissa.is_finite() || mantissa == 0.0 {
+			return Decimal::new(mantissa);
+		}
+
+		let value = mantissa * 2.0_f64.powi(exp2);
+		if value.is_normal() {
+			return Decimal::new(value);
+		}
+
+		let log10 = exp2 as f64 * LOG10_2;
+		let exponent = log10.floor();
+		from_mantissa_exponent(mantissa * 10.0_f64.powf(log10 - exponent), exponent)
+	}
+
+	/// Creates a new instance of Decimal with the value `num / den`, found by long division to the given number of
+	/// significant digits, rounding half up, so it is not first rounded to an f64 like `Decimal::new(num / den)`.
+	/// At most [MAX_SIGNIFICANT_DIGITS] digits are kept, as the mantissa cannot hold more. Returns NaN if den is zero.
+	pub fn from_rational(num: i64, den: i64, sig_digits: u32) -> Decimal {
+		if den == 0 {
+			return Decimal::NAN;
+		} else if num == 0 {
+			return Decimal::ZERO;
+		}
+
+		let mut remainder = num.unsigned_abs() as u128;
+		let mut divisor = den.unsigned_abs() as u128;
+		let mut exponent = 0_i32;
+		while remainder >= divisor * 10 {
+			divisor *= 10;
+			exponent += 1;
+		}
+		while remainder < divisor {
+			remainder *= 10;
+			exponent -= 1;
+		}
+
+		let digits = sig_digits.clamp(1, MAX_SIGNIFICANT_DIGITS);
+		let mut significand = 0_u128;
+		for _ in 0..digits {
+			significand = significand * 10 + remainder / divisor;
+			remainder = remainder % divisor * 10;
+		}
+		if remainder / divisor >= 5 {
+			significand += 1;
+		}
+
+		// Parsing the digits rounds them to the mantissa only once.
+		let mantissa: f64 = format!("{}e-{}", significand, digits - 1).parse().unwrap();
+		let sign = if (num < 0) != (den < 0) { -1.0 } else { 1.0 };
+		from_mantissa_exponent(sign * mantissa, exponent as f64)
+	}
+
+	fn new_with_rounding(value: f64, rounding: f64) -> Decimal {
 		// SAFETY: Handle Infinity and NaN in a somewhat meaningful way.
 		if f64::is_nan(value) {
 			return Decimal {
@@ -559,14 +935,24 @@ impl Decimal {
 			};
 		}
 
-		let e = value.abs().log10().floor();
-		let m = if (e - NUMBER_EXP_MIN as f64).abs() < f64::EPSILON {
+		let mut e = value.abs().log10().floor();
+		let mut m = if (e - NUMBER_EXP_MIN as f64).abs() < f64::EPSILON {
 			value * 10.0 / format!("1e{}", NUMBER_EXP_MIN + 1).parse::<f64>().unwrap()
 		} else {
 			let power_10 = power_of_10(e as i32);
 			// This essentially rounds the mantissa for very high numbers.
-			((value / power_10) * 1000000000000000.0).round() / 1000000000000000.0
+			((value / power_10) * rounding).round() / rounding
 		};
+
+		// log10 may be off by one at powers of 10, so correct the exponent against the rounded mantissa.
+		if m.abs() >= 10.0 {
+			m /= 10.0;
+			e += 1.0;
+		} else if m.abs() < 1.0 {
+			m *= 10.0;
+			e -= 1.0;
+		}
+
 		let decimal = Decimal {
 			mantissa: m,
 			exponent: e,
@@ -574,7 +960,37 @@ impl Decimal {
 		decimal.normalize()
 	}
 
+	/// Creates a new instance of Decimal from a string of decimal digits of any length, with an optional sign.
+	///
+	/// Unlike [FromStr], this does not go through an f64, so integers beyond its range keep their leading digits.
+	pub fn from_digits(digits: &str) -> Result<Decimal, ParseDecimalError> {
+		let (sign, digits) = match digits.strip_prefix('-') {
+			Some(digits) => (-1.0, digits),
+			None => (1.0, digits.strip_prefix('+').unwrap_or(digits)),
+		};
+
+		if digits.is_empty() {
+			return Err(ParseDecimalError::Empty);
+		} else if !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+			return Err(ParseDecimalError::InvalidDigit);
+		}
+
+		let significant = digits.trim_start_matches('0');
+		if significant.is_empty() {
+			return Ok(Decimal::ZERO);
+		}
+
+		let leading = &significant[..significant.len().min(MAX_SIGNIFICANT_DIGITS as usize)];
+		let mantissa: f64 = format!("{}.{}", &leading[..1], &leading[1..]).parse().unwrap();
+		Ok(from_mantissa_exponent(sign * mantissa, (significant.len() - 1) as f64))
+	}
+
 	pub fn pow10(power: f64) -> Decimal {
+		Decimal::pow10_unfinished(power).finish_arithmetic()
+	}
+
+	/// Creates 10 to the given power without the post-processing of [Decimal::finish_arithmetic].
+	fn pow10_unfinished(power: f64) -> Decimal {
 		if power.fract() == 0.0 {
 			from_mantissa_exponent_no_normalize(1.0, power)
 		} else {
@@ -582,71 +998,296 @@ impl Decimal {
 		}
 	}
 
-	/// Normalizes the mantissa when it is too denormalized.
-	fn normalize(&self) -> Decimal {
-		if self.mantissa >= 1.0 && self.mantissa < 10.0 {
-			return *self;
-		} else if self.mantissa == 0.0 {
-			return Decimal {
-				mantissa: 0.0,
-				exponent: 0.0,
-			};
+	/// Interpolates between the two Decimals on a logarithmic scale, where `t` ranges from 0 to 1.
+	/// Returns NaN if either of them is not positive.
+	pub fn log_lerp(a: &Decimal, b: &Decimal, t: f64) -> Decimal {
+		if a.mantissa <= 0.0 || b.mantissa <= 0.0 {
+			return Decimal::NAN;
 		}
 
-		let temp_exponent = self.mantissa.abs().log10().floor();
-		Decimal {
-			mantissa: if (temp_exponent as i32) == NUMBER_EXP_MIN {
-				self.mantissa * 10.0 / 1e-323
-			} else {
-				self.mantissa / power_of_10(temp_exponent as i32)
-			},
-			exponent: self.exponent + temp_exponent,
-		}
+		let a_log = a.log10();
+		Decimal::pow10(a_log + (b.log10() - a_log) * t)
 	}
 
-	/// Converts the Decimal to an f64.
-	pub fn to_number(&self) -> f64 {
-		//  Problem: new(116.0).to_number() returns 115.99999999999999.
-		//  TODO: How to fix in general case? It's clear that if to_number() is
-		//	VERY close to an integer, we want exactly the integer.
-		//	But it's not clear how to specifically write that.
-		//	So I'll just settle with 'exponent >= 0 and difference between rounded
-		//	and not rounded < 1e-9' as a quick fix.
-		//  var result = self.mantissa * 10.0_f64.powf(self.exponent);
-		if !f64::is_finite(self.exponent) {
-			return f64::NAN;
+	/// Applies the feature-dependent post-processing to the result of an arithmetic operation.
+	/// With the `nan-on-overflow` feature, a result past the exponent limit is replaced with NaN.
+	/// With the `deterministic` feature, the mantissa is canonicalized with [Decimal::canonicalize_precision].
+	#[inline(always)]
+	fn finish_arithmetic(self) -> Decimal {
+		#[cfg(feature = "nan-on-overflow")]
+		if self.exponent > EXP_LIMIT {
+			return Decimal::NAN;
 		}
 
-		if self.exponent > NUMBER_EXP_MAX as f64 {
-			return if self.mantissa > 0.0 {
-				f64::INFINITY
-			} else {
-				f64::NEG_INFINITY
-			};
-		}
+		#[cfg(feature = "deterministic")]
+		return self.canonicalize_precision();
 
-		if self.exponent < NUMBER_EXP_MIN as f64 {
-			return 0.0;
-		}
+		#[cfg(not(feature = "deterministic"))]
+		self
+	}
 
-		if (self.exponent - NUMBER_EXP_MIN as f64).abs() < f64::EPSILON {
-			return if self.mantissa > 0.0 { 5e-324 } else { -5e-324 };
+	/// Adds the given Decimal without the post-processing of [Decimal::finish_arithmetic].
+	fn add_unfinished(self, decimal: Decimal) -> Decimal {
+		// Figure out which is bigger, shrink the mantissa of the smaller
+		// by the difference in exponents, add mantissas, normalize and return
+		// TODO: Optimizations and simplification may be possible, see https://github.com/Patashu/break_infinity.js/issues/8
+		if self.is_nan() || decimal.is_nan() {
+			return Decimal::NAN;
 		}
 
-		let result: f64 = self.mantissa * power_of_10(self.exponent as i32);
-
-		if !f64::is_finite(result) || self.exponent < 0.0 {
-			return result;
+		if self.mantissa == 0.0 {
+			return decimal;
 		}
 
-		let result_rounded = result.round();
-
-		if (result_rounded - result).abs() < ROUND_TOLERANCE {
-			return result_rounded;
+		if decimal.mantissa == 0.0 {
+			return self;
 		}
 
-		result
-	}
+		let bigger_decimal;
+		let smaller_decimal;
+
+		if self.exponent >= decimal.exponent {
+			bigger_decimal = self;
+			smaller_decimal = decimal;
+		} else {
+			bigger_decimal = decimal;
+			smaller_decimal = self;
+		}
+
+		if bigger_decimal.exponent - smaller_decimal.exponent > MAX_SIGNIFICANT_DIGITS as f64 {
+			return bigger_decimal;
+		}
+
+		from_mantissa_exponent(
+			(1e14 * bigger_decimal.mantissa)
+				+ 1e14
+					* smaller_decimal.mantissa
+					* power_of_10((smaller_decimal.exponent - bigger_decimal.exponent) as i32),
+			bigger_decimal.exponent - 14.0,
+		)
+	}
+
+	/// Multiplies by the given Decimal without the post-processing of [Decimal::finish_arithmetic].
+	fn mul_unfinished(self, decimal: Decimal) -> Decimal {
+		if self.is_nan() || decimal.is_nan() {
+			return Decimal::NAN;
+		}
+
+		from_mantissa_exponent(self.mantissa * decimal.mantissa, self.exponent + decimal.exponent)
+	}
+
+	/// Rounds the mantissa to 15 significant digits, so that platforms which agree on those digits
+	/// produce identical Decimals. This trades a little accuracy for cross-platform determinism.
+	pub fn canonicalize_precision(&self) -> Decimal {
+		if !self.mantissa.is_finite() {
+			return *self;
+		}
+
+		let mantissa = (self.mantissa * 1e14).round() / 1e14;
+		if mantissa.abs() >= 10.0 {
+			from_mantissa_exponent_no_normalize(mantissa / 10.0, self.exponent + 1.0)
+		} else {
+			from_mantissa_exponent_no_normalize(mantissa, self.exponent)
+		}
+	}
+
+	/// Normalizes the mantissa when it is too denormalized.
+	fn normalize(&self) -> Decimal {
+		if self.mantissa >= 1.0 && self.mantissa < 10.0 {
+			return *self;
+		} else if self.mantissa == 0.0 {
+			return Decimal {
+				mantissa: 0.0,
+				exponent: 0.0,
+			};
+		} else if f64::is_nan(self.mantissa) || f64::is_nan(self.exponent) {
+			return Decimal {
+				mantissa: f64::NAN,
+				exponent: f64::NAN,
+			};
+		} else if f64::is_infinite(self.mantissa) {
+			return Decimal {
+				mantissa: self.mantissa.signum(),
+				exponent: EXP_LIMIT,
+			};
+		}
+
+		let temp_exponent = self.mantissa.abs().log10().floor();
+		let temp_exponent_int = temp_exponent as i32;
+		Decimal {
+			mantissa: if temp_exponent_int == NUMBER_EXP_MIN {
+				self.mantissa * 10.0 / 1e-323
+			} else if (NUMBER_EXP_MIN..=NUMBER_EXP_MAX).contains(&temp_exponent_int) {
+				self.mantissa / power_of_10(temp_exponent_int)
+			} else {
+				self.mantissa / 10.0_f64.powi(temp_exponent_int)
+			},
+			exponent: self.exponent + temp_exponent,
+		}
+	}
+
+	/// Returns the mantissa and the exponent of the Decimal as an integer,
+	/// or None if the exponent is not finite or does not fit in an i32.
+	pub fn to_mantissa_exp_i32(&self) -> Option<(f64, i32)> {
+		if self.exponent.is_finite() && self.exponent >= i32::MIN as f64 && self.exponent <= i32::MAX as f64 {
+			Some((self.mantissa, self.exponent as i32))
+		} else {
+			None
+		}
+	}
+
+	/// Returns the magnitude of the Decimal in the "layer" convention of break_eternity.js.
+	///
+	/// At layer 0, the residual is the absolute value of the Decimal, which is used when it fits in an f64.
+	/// Otherwise, the layer is 1 and the residual is the log10 of the absolute value, so that
+	/// the absolute value is `10^residual`. The sign is not included, see [Decimal::sign].
+	pub fn to_layer_mantissa(&self) -> (u32, f64) {
+		let number = self.abs().to_number();
+		if self.mantissa == 0.0 || self.is_nan() || (number.is_finite() && number != 0.0) {
+			(0, number)
+		} else {
+			(1, self.abs_log10())
+		}
+	}
+
+	/// Returns an iterator over the decimal digits of the integer part of the floored Decimal,
+	/// from the most to the least significant, ignoring the sign.
+	///
+	/// Digits beyond the precision of the mantissa are yielded as zeros, without building a string of them.
+	/// Non-finite Decimals have no digits.
+	// std::iter::repeat_n would need Rust 1.82.
+	#[allow(clippy::manual_repeat_n)]
+	pub fn integer_digit_iter(&self) -> impl Iterator<Item = u8> {
+		let floored = self.floor();
+		let (digits, zeros) = if floored.as_non_finite_string().is_some() {
+			(String::new(), 0.0)
+		} else if floored.exponent < MAX_SIGNIFICANT_DIGITS as f64 {
+			(floored.to_number().abs().to_string(), 0.0)
+		} else {
+			let digits = floored.mantissa.abs().to_string().replace('.', "");
+			let zeros = floored.exponent + 1.0 - digits.len() as f64;
+			(digits, zeros)
+		};
+
+		digits
+			.into_bytes()
+			.into_iter()
+			.map(|digit| digit - b'0')
+			.chain(std::iter::repeat(0).take(zeros as usize))
+	}
+
+	/// Packs the Decimal into an opaque key, such that equal Decimals have equal keys.
+	/// The key is not a numeric value, and does not preserve ordering.
+	pub fn to_u128_key(&self) -> u128 {
+		let (mantissa, exponent) = if self.is_nan() {
+			(f64::NAN, 0.0)
+		} else if self.mantissa == 0.0 {
+			(0.0, 0.0)
+		} else {
+			// Adding zero turns negative zero into positive zero.
+			(self.mantissa, self.exponent + 0.0)
+		};
+
+		((mantissa.to_bits() as u128) << 64) | exponent.to_bits() as u128
+	}
+
+	/// Returns a deterministic pseudo-random number in `[0, 1)` derived from the Decimal,
+	/// by hashing [Decimal::to_u128_key] with splitmix64. Equal Decimals give equal numbers on every platform.
+	pub fn hash_to_unit(&self) -> f64 {
+		fn splitmix64(mut x: u64) -> u64 {
+			x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+			x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+			x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+			x ^ (x >> 31)
+		}
+
+		let key = self.to_u128_key();
+		let hash = splitmix64(splitmix64(key as u64) ^ (key >> 64) as u64);
+		// The top 53 bits fill the mantissa of an f64 exactly.
+		(hash >> 11) as f64 / (1u64 << 53) as f64
+	}
+
+	/// Encodes the exact bits of the mantissa and exponent into a compact, URL-safe base-62 string.
+	pub fn to_base62(&self) -> String {
+		let mut bits = ((self.mantissa.to_bits() as u128) << 64) | self.exponent.to_bits() as u128;
+		if bits == 0 {
+			return String::from("0");
+		}
+
+		let mut digits = Vec::new();
+		while bits > 0 {
+			digits.push(BASE62_DIGITS[(bits % 62) as usize]);
+			bits /= 62;
+		}
+		digits.reverse();
+		String::from_utf8(digits).unwrap()
+	}
+
+	/// Decodes a Decimal from a string created by [Decimal::to_base62].
+	pub fn from_base62(string: &str) -> Result<Decimal, ParseDecimalError> {
+		if string.is_empty() {
+			return Err(ParseDecimalError::Empty);
+		}
+
+		let mut bits: u128 = 0;
+		for byte in string.bytes() {
+			let digit = BASE62_DIGITS
+				.iter()
+				.position(|&digit| digit == byte)
+				.ok_or(ParseDecimalError::InvalidDigit)?;
+			bits = bits
+				.checked_mul(62)
+				.and_then(|bits| bits.checked_add(digit as u128))
+				.ok_or(ParseDecimalError::Overflow)?;
+		}
+
+		Ok(from_mantissa_exponent_no_normalize(
+			f64::from_bits((bits >> 64) as u64),
+			f64::from_bits(bits as u64),
+		))
+	}
+
+	/// Converts the Decimal to an f64.
+	pub fn to_number(&self) -> f64 {
+		//  Problem: new(116.0).to_number() returns 115.99999999999999.
+		//	If to_number() is VERY close to an integer, we want exactly the integer,
+		//	so results with exponent >= 0 within `NUMBER_ROUND_TOLERANCE` of an integer,
+		//	relative to their magnitude, are snapped to it.
+		//  var result = self.mantissa * 10.0_f64.powf(self.exponent);
+		if !f64::is_finite(self.exponent) {
+			return f64::NAN;
+		}
+
+		if self.exponent > NUMBER_EXP_MAX as f64 {
+			return if self.mantissa > 0.0 {
+				f64::INFINITY
+			} else {
+				f64::NEG_INFINITY
+			};
+		}
+
+		if self.exponent < NUMBER_EXP_MIN as f64 {
+			return 0.0;
+		}
+
+		if (self.exponent - NUMBER_EXP_MIN as f64).abs() < f64::EPSILON {
+			return if self.mantissa > 0.0 { 5e-324 } else { -5e-324 };
+		}
+
+		let result: f64 = self.mantissa * power_of_10(self.exponent as i32);
+
+		if !f64::is_finite(result) || self.exponent < 0.0 {
+			return result;
+		}
+
+		let result_rounded = result.round();
+
+		if (result_rounded - result).abs() < result.abs() * NUMBER_ROUND_TOLERANCE {
+			return result_rounded;
+		}
+
+		result
+	}
 
 	#[inline(always)]
 	fn as_non_finite_string(&self) -> Option<String> {
@@ -663,6 +1304,90 @@ impl Decimal {
 		}
 	}
 
+	/// Converts the Decimal into a string, using the standard notation for exponents from `min_exponent` up to 20,
+	/// rather than only from -6 as [Display] does.
+	pub fn to_string_with_min_exponent(&self, min_exponent: i32) -> String {
+		if self.as_non_finite_string().is_some()
+			|| self.exponent <= -EXP_LIMIT
+			|| self.mantissa == 0.0
+			|| self.exponent >= 21.0
+		{
+			self.to_string()
+		} else if self.exponent >= min_exponent as f64 {
+			self.standard_notation_string()
+		} else {
+			self.to_exponential(16)
+		}
+	}
+
+	/// Converts the Decimal into a string with the standard notation.
+	/// Negative exponents shift the digits of the mantissa, which avoids floating-point error from scaling it.
+	fn standard_notation_string(&self) -> String {
+		if self.exponent >= 0.0 {
+			return self.to_number().to_string();
+		}
+
+		let sign = if self.mantissa < 0.0 { "-" } else { "" };
+		let digits = self.mantissa.abs().to_string().replace('.', "");
+		let zeros = "0".repeat((-self.exponent - 1.0) as usize);
+		format!("{}0.{}{}", sign, zeros, digits)
+	}
+
+	/// Converts the Decimal into the shortest string which parses back to it, using the standard notation
+	/// for exponents from -6 up to 20 and the scientific notation otherwise.
+	///
+	/// The digits are those of the shortest round-trip representation of the mantissa as an `f64`,
+	/// shifted by the exponent, so there are no trailing zeros or excess digits.
+	pub fn to_shortest(&self) -> String {
+		if let Some(string) = self.as_non_finite_string() {
+			return string;
+		} else if self.exponent <= -EXP_LIMIT || self.mantissa == 0.0 {
+			return String::from("0");
+		} else if self.exponent <= -7.0 || self.exponent >= 21.0 {
+			let sign = if self.exponent >= 0.0 { "+" } else { "" };
+			return format!("{}e{}{}", self.mantissa, sign, self.exponent);
+		}
+
+		let sign = if self.mantissa < 0.0 { "-" } else { "" };
+		let digits = self.mantissa.abs().to_string().replace('.', "");
+		if self.exponent < 0.0 {
+			let zeros = "0".repeat((-self.exponent - 1.0) as usize);
+			return format!("{}0.{}{}", sign, zeros, digits);
+		}
+
+		let point = self.exponent as usize + 1;
+		if digits.len() <= point {
+			format!("{}{}{}", sign, digits, "0".repeat(point - digits.len()))
+		} else {
+			format!("{}{}.{}", sign, &digits[..point], &digits[point..])
+		}
+	}
+
+	/// Converts the Decimal into a string identical to the output of `toString()` in break_infinity.js.
+	pub fn to_js_string(&self) -> String {
+		if let Some(string) = self.as_non_finite_string() {
+			return string;
+		} else if self.exponent <= -EXP_LIMIT || self.mantissa == 0.0 {
+			return String::from("0");
+		} else if self.exponent < 21.0 && self.exponent > -7.0 {
+			// break_infinity.js rounds to integers with a tolerance of 1e-10 rather than `ROUND_TOLERANCE`.
+			let number = self.to_number();
+			let rounded = number.round();
+			if self.exponent >= 0.0 && (rounded - number).abs() < 1e-10 {
+				return to_js_number_string(rounded);
+			}
+			return to_js_number_string(number);
+		}
+
+		let sign = if self.exponent >= 0.0 { "+" } else { "" };
+		format!(
+			"{}e{}{}",
+			to_js_number_string(self.mantissa),
+			sign,
+			to_js_number_string(self.exponent)
+		)
+	}
+
 	/// Converts the Decimal into a string with the scientific notation.
 	pub fn to_exponential(&self, mut places: u32) -> String {
 		if let Some(string) = self.as_non_finite_string() {
@@ -684,7 +1409,7 @@ impl Decimal {
 		let rounded = (self.mantissa * 10.0_f64.powi(len as i32 - num_digits as i32)).round()
 			* 10.0_f64.powi(num_digits as i32 - len as i32);
 
-		let mantissa = to_fixed(rounded, 0_u32.max(len - num_digits));
+		let mantissa = to_fixed(rounded, len.saturating_sub(num_digits));
 		let sign = if self.exponent >= 0.0 { "+" } else { "" };
 		format!("{}e{}{}", mantissa, sign, self.exponent)
 	}
@@ -715,6 +1440,26 @@ impl Decimal {
 		to_fixed(self.to_number(), places)
 	}
 
+	/// Returns whether the Decimal and the given Decimal are displayed the same by [Decimal::to_fixed]
+	/// with the given number of places, such as to skip redrawing a value whose display has not changed.
+	pub fn displays_same_as(&self, other: &Decimal, places: u32) -> bool {
+		if self == other {
+			return true;
+		}
+
+		// The larger one shows a nonzero digit that the other, a tenth of it or less, cannot round up to.
+		let larger_exponent = self.exponent.max(other.exponent);
+		if self.mantissa != 0.0
+			&& other.mantissa != 0.0
+			&& (self.exponent - other.exponent).abs() > 1.0
+			&& larger_exponent >= -(places as f64)
+		{
+			return false;
+		}
+
+		self.to_fixed(places) == other.to_fixed(places)
+	}
+
 	/// Converts the Decimal into a string with the scientific notation if the exponent is greater than the precision.
 	pub fn to_precision(&self, places: u32) -> String {
 		if self.exponent <= -7.0 {
@@ -728,6 +1473,218 @@ impl Decimal {
 		self.to_exponential(places - 1)
 	}
 
+	/// Converts the Decimal into a string with the given number of significant figures,
+	/// using the standard notation for exponents from -6 up to 20 and the scientific notation otherwise.
+	pub fn to_significant(&self, figures: u32) -> String {
+		if let Some(string) = self.as_non_finite_string() {
+			return string;
+		} else if self.exponent <= -EXP_LIMIT || self.mantissa == 0.0 {
+			return String::from("0");
+		}
+
+		// The mantissa has no more significant digits to show past this.
+		let places = figures.clamp(1, MAX_SIGNIFICANT_DIGITS) - 1;
+		let scale = power_of_10(places as i32);
+		let mut mantissa = (self.mantissa * scale).round() / scale;
+		let mut exponent = self.exponent;
+		if mantissa.abs() >= 10.0 {
+			mantissa /= 10.0;
+			exponent += 1.0;
+		}
+
+		if exponent > -7.0 && exponent < 21.0 {
+			let decimals = (places as f64 - exponent).max(0.0) as u32;
+			to_fixed(mantissa * power_of_10(exponent as i32), decimals)
+		} else {
+			let sign = if exponent >= 0.0 { "+" } else { "" };
+			format!("{}e{}{}", to_fixed(mantissa, places), sign, exponent)
+		}
+	}
+
+	/// Converts the Decimal into a string with the scientific notation, such as `1.50×10⁴⁰`,
+	/// writing the exponent with Unicode superscript digits.
+	pub fn to_superscript(&self, places: u32) -> String {
+		const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+		if let Some(string) = self.as_non_finite_string() {
+			return string;
+		}
+
+		let (mut mantissa, mut exponent) = if self.exponent <= -EXP_LIMIT {
+			(0.0, 0.0)
+		} else {
+			(self.mantissa, self.exponent)
+		};
+		// Rounding past the significant digits of the mantissa can never carry it up to 10.
+		let scale = power_of_10(places.min(MAX_SIGNIFICANT_DIGITS - 1) as i32);
+		if (mantissa * scale).round().abs() >= 10.0 * scale {
+			mantissa /= 10.0;
+			exponent += 1.0;
+		}
+
+		let superscript: String = exponent
+			.to_string()
+			.chars()
+			.map(|c| match c.to_digit(10) {
+				Some(digit) => SUPERSCRIPTS[digit as usize],
+				None => '⁻',
+			})
+			.collect();
+		format!("{}×10{}", to_fixed(mantissa, places), superscript)
+	}
+
+	/// Renders a bar of `#` characters whose length is proportional to the Decimal relative to `max`,
+	/// out of the given width. The bar is empty if `max` is zero, and full if the Decimal exceeds `max`.
+	pub fn to_bar(&self, max: &Decimal, width: usize) -> String {
+		if max.mantissa == 0.0 {
+			return String::new();
+		}
+
+		let ratio = (self / max).to_number();
+		let length = if ratio.is_nan() {
+			0
+		} else {
+			(width as f64 * ratio).round().clamp(0.0, width as f64) as usize
+		};
+		"#".repeat(length)
+	}
+
+	/// Converts the Decimal into a string with its short-scale name, such as `1.50 billion`,
+	/// with the given number of decimal places. Values below a thousand are written without a name,
+	/// and values past the vigintillions are written in scientific notation.
+	pub fn to_illion_name(&self, decimals: u32) -> String {
+		if let Some(string) = self.as_non_finite_string() {
+			return string;
+		}
+
+		let mut tier = self.abbreviation_tier();
+		if tier == 0 {
+			let fixed = self.to_fixed(decimals);
+			let integer_digits = fixed.trim_start_matches('-').split('.').next().map_or(0, str::len);
+			if integer_digits < 4 {
+				return fixed;
+			}
+			// It was rounded up to a thousand.
+			tier = 1;
+		}
+		if tier as usize > ILLION_NAMES.len() {
+			return self.to_exponential(decimals);
+		}
+
+		let scaled = self.mantissa * power_of_10((self.exponent - 3.0 * tier as f64) as i32);
+		let mut fixed = to_fixed(scaled, decimals);
+		if fixed.trim_start_matches('-').starts_with("1000") {
+			tier += 1;
+			fixed = to_fixed(scaled / 1000.0, decimals);
+		}
+
+		match ILLION_NAMES.get(tier as usize - 1) {
+			Some(name) => format!("{} {}", fixed, name),
+			None => self.to_exponential(decimals),
+		}
+	}
+
+	/// Converts the Decimal into a string with the scientific notation, with the mantissa written in exactly
+	/// the given number of characters, including its sign and decimal point, so that columns of Decimals line up.
+	/// The mantissa is padded with spaces if it is too wide to have any decimal places,
+	/// and widened to fit if it is too narrow for the sign and a single digit.
+	pub fn to_aligned(&self, mantissa_chars: usize) -> String {
+		if let Some(string) = self.as_non_finite_string() {
+			return format!("{:>1$}", string, mantissa_chars);
+		}
+
+		let (mut mantissa, mut exponent) = if self.exponent <= -EXP_LIMIT || self.mantissa == 0.0 {
+			(0.0, 0.0)
+		} else {
+			(self.mantissa, self.exponent)
+		};
+
+		// One character for the sign, if any, then one for the leading digit and one for the decimal point.
+		let sign_chars = (mantissa < 0.0) as usize;
+		let mantissa_chars = mantissa_chars.max(sign_chars + 1);
+		let places = (mantissa_chars - sign_chars).saturating_sub(2);
+		if to_fixed(mantissa.abs(), places as u32).starts_with("10") {
+			mantissa /= 10.0;
+			exponent += 1.0;
+		}
+
+		let sign = if exponent >= 0.0 { "+" } else { "" };
+		format!(
+			"{:<3$}e{}{}",
+			to_fixed(mantissa, places as u32),
+			sign,
+			exponent,
+			mantissa_chars
+		)
+	}
+
+	/// Converts the Decimal as a number of seconds into a human-readable duration, such as `1h 1m 1s`.
+	/// Durations of a million years or more are written as a number of years in scientific notation.
+	pub fn to_duration_string(&self) -> String {
+		const UNITS: [(&str, f64); 5] = [
+			("y", 31_536_000.0),
+			("d", 86_400.0),
+			("h", 3_600.0),
+			("m", 60.0),
+			("s", 1.0),
+		];
+
+		if let Some(string) = self.as_non_finite_string() {
+			return string;
+		} else if self.mantissa < 0.0 {
+			return format!("-{}", self.neg().to_duration_string());
+		}
+
+		let years = self / Decimal::new(UNITS[0].1);
+		if years.exponent >= 6.0 {
+			return format!("{}y", years.to_exponential(2));
+		}
+
+		let mut seconds = self.to_number();
+		if seconds < 1.0 {
+			return format!("{}s", to_fixed_num(seconds, 3));
+		}
+
+		let mut parts = Vec::new();
+		for (unit, length) in UNITS {
+			let amount = (seconds / length).floor();
+			if amount > 0.0 {
+				parts.push(format!("{}{}", amount, unit));
+				seconds -= amount * length;
+			}
+		}
+
+		parts.join(" ")
+	}
+
+	/// Rounds the mantissa to a multiple of `2^-mantissa_bits`, keeping the exponent.
+	///
+	/// This makes the Decimal more compressible, at the cost of a relative error of up to `2^-(mantissa_bits + 1)`.
+	pub fn quantize(&self, mantissa_bits: u32) -> Decimal {
+		if mantissa_bits >= f64::MANTISSA_DIGITS || !self.mantissa.is_finite() {
+			return *self;
+		}
+
+		let scale = 2.0_f64.powi(mantissa_bits as i32);
+		from_mantissa_exponent((self.mantissa * scale).round() / scale, self.exponent)
+	}
+
+	/// Rounds the mantissa to the nearest value with `bits` bits of binary precision, including the implicit leading bit,
+	/// and re-normalizes. This matches storing the mantissa in a narrower float,
+	/// such as 24 bits for an [f32] mantissa or 8 bits for a bf16 mantissa, without the f32 exponent range limits.
+	pub fn round_mantissa_bits(&self, bits: u32) -> Decimal {
+		if bits >= f64::MANTISSA_DIGITS || !self.mantissa.is_finite() || self.mantissa == 0.0 {
+			return *self;
+		}
+
+		let dropped = f64::MANTISSA_DIGITS - bits.max(1);
+		let half = 1_u64 << (dropped - 1);
+		let mask = !((1_u64 << dropped) - 1);
+		// A carry out of the stored mantissa correctly moves up to the next power of 2.
+		let mantissa = f64::from_bits((self.mantissa.to_bits() + half) & mask);
+		from_mantissa_exponent(mantissa, self.exponent)
+	}
+
 	/// Returns the mantissa with the specified precision.
 	pub fn mantissa_with_decimal_places(&self, places: u32) -> f64 {
 		// https://stackoverflow.com/a/37425022
@@ -741,7 +1698,7 @@ impl Decimal {
 		let num_digits = self.mantissa.abs().log10().ceil() as u32;
 		let rounded = (self.mantissa * 10.0_f64.powi(len as i32 - num_digits as i32)).round()
 			* 10.0_f64.powi(num_digits as i32 - len as i32);
-		to_fixed_num(rounded, 0.max(len - num_digits))
+		to_fixed_num(rounded, len.saturating_sub(num_digits))
 	}
 
 	/// Returns the absolute value of the Decimal.
@@ -749,9 +1706,136 @@ impl Decimal {
 		from_mantissa_exponent_no_normalize(self.mantissa.abs(), self.exponent)
 	}
 
-	/// Returns the sign of the Decimal, according to [f64::signum].
+	/// Returns true if the Decimal is NaN.
+	pub fn is_nan(&self) -> bool {
+		f64::is_nan(self.mantissa) || f64::is_nan(self.exponent)
+	}
+
+	/// Returns true if raising the Decimal to the given power would produce NaN,
+	/// which is when the Decimal is negative and the power is not an integer, or either is NaN.
+	pub fn would_be_nan_pow(&self, exp: &Decimal) -> bool {
+		self.is_nan() || exp.is_nan() || (self.mantissa < 0.0 && exp.to_number().fract() != 0.0)
+	}
+
+	/// Returns true if the square root of the Decimal would produce NaN, which is when it is negative or NaN.
+	pub fn would_be_nan_sqrt(&self) -> bool {
+		self.is_nan() || self.mantissa < 0.0
+	}
+
+	/// Returns true if a logarithm of the Decimal, such as [Decimal::ln] or [Decimal::log10], would not be a number,
+	/// which is when it is not positive or is NaN. The logarithm of zero is negative infinity rather than NaN,
+	/// but is included as it poisons later arithmetic just the same.
+	pub fn would_be_nan_log(&self) -> bool {
+		self.is_nan() || self.mantissa <= 0.0
+	}
+
+	/// Returns true if the Decimal is an exact positive power of 10.
+	pub fn is_power_of_ten(&self) -> bool {
+		(self.mantissa - 1.0).abs() <= ROUND_TOLERANCE && self.exponent.fract() == 0.0
+	}
+
+	/// Returns how many factors of ten divide the Decimal, counting the trailing zeros of its integer representation.
+	/// Returns 0 for zero, non-finite and non-integer Decimals.
+	pub fn trailing_zeros(&self) -> i64 {
+		if self.as_non_finite_string().is_some() || self.mantissa == 0.0 {
+			return 0;
+		}
+
+		let mantissa = self.mantissa.abs().to_string();
+		let fraction_digits = mantissa.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+		(self.exponent - fraction_digits as f64).max(0.0) as i64
+	}
+
+	/// Returns the tier of the suffix that abbreviates the magnitude of the Decimal, such as 1 for K (thousands)
+	/// and 2 for M (millions), or 0 for magnitudes below 1000. Tiers past [i32::MAX] are saturated to it.
+	pub fn abbreviation_tier(&self) -> i32 {
+		if self.is_nan() || self.mantissa == 0.0 || self.exponent < 3.0 {
+			0
+		} else {
+			(self.exponent / 3.0).floor() as i32
+		}
+	}
+
+	/// Returns how many tiers, each tierRatio times larger than the last, the Decimal is above the reference,
+	/// which is negative when it is below. Returns 0 if either Decimal is not positive or the ratio is not greater than 1.
+	pub fn tier_delta(&self, reference: &Decimal, tier_ratio: &Decimal) -> i64 {
+		if self.mantissa <= 0.0 || reference.mantissa <= 0.0 || tier_ratio <= &Decimal::ONE {
+			return 0;
+		}
+
+		let tiers = (self.log10() - reference.log10()) / tier_ratio.log10();
+		if tiers.is_nan() {
+			return 0;
+		}
+
+		// Don't let floating-point error in the logarithms drop a value exactly on a tier to the one below.
+		let rounded = tiers.round();
+		if (rounded - tiers).abs() < 1e-10 {
+			rounded as i64
+		} else {
+			tiers.floor() as i64
+		}
+	}
+
+	/// Returns the smallest power of 10 which is greater than or equal to the Decimal,
+	/// or NaN if the Decimal is not positive, as no such power exists.
+	pub fn ceil_power_of_ten(&self) -> Decimal {
+		if self.mantissa <= 0.0 || self.is_nan() {
+			Decimal::NAN
+		} else if self.is_power_of_ten() {
+			from_mantissa_exponent_no_normalize(1.0, self.exponent)
+		} else {
+			from_mantissa_exponent_no_normalize(1.0, self.exponent + 1.0)
+		}
+	}
+
+	/// Returns the smallest multiple of 10^`exp` which is greater than or equal to the Decimal.
+	pub fn ceil_at_exponent(&self, exp: i32) -> Decimal {
+		let shift = self.exponent - exp as f64;
+		if self.is_nan() || shift >= MAX_SIGNIFICANT_DIGITS as f64 {
+			return *self;
+		} else if shift < 0.0 {
+			return if self.mantissa > 0.0 {
+				from_mantissa_exponent_no_normalize(1.0, exp as f64)
+			} else {
+				Decimal::ZERO
+			};
+		}
+
+		// Snap to the nearest integer first, so that floating-point error cannot push it up to the next one.
+		let scaled = self.mantissa * power_of_10(shift as i32);
+		let rounded = scaled.round();
+		let multiple = if (rounded - scaled).abs() < scaled.abs() * NUMBER_ROUND_TOLERANCE {
+			rounded
+		} else {
+			scaled.ceil()
+		};
+
+		from_mantissa_exponent(multiple, exp as f64)
+	}
+
+	/// Returns the largest power of 10 which is less than or equal to the Decimal,
+	/// or NaN if the Decimal is not positive, as no such power exists.
+	pub fn floor_power_of_ten(&self) -> Decimal {
+		if self.mantissa <= 0.0 || self.is_nan() {
+			Decimal::NAN
+		} else {
+			from_mantissa_exponent_no_normalize(1.0, self.exponent)
+		}
+	}
+
+	/// Returns the sign of the Decimal, according to [f64::signum], except that zero has a sign of 0.
 	pub fn sign(&self) -> f64 {
-		self.mantissa.signum()
+		if self.mantissa == 0.0 {
+			0.0
+		} else {
+			self.mantissa.signum()
+		}
+	}
+
+	/// Returns the sign and the absolute value of the Decimal.
+	pub fn split_sign(&self) -> (f64, Decimal) {
+		(self.sign(), self.abs())
 	}
 
 	/// Rounds the Decimal, if the exponent isn't greater than the maximum significant digits.
@@ -765,6 +1849,25 @@ impl Decimal {
 		}
 	}
 
+	/// Rounds the Decimal to the nearest integer multiple of the given Decimal, or returns it unchanged if that is zero.
+	pub fn round_to_multiple(&self, multiple: &Decimal) -> Decimal {
+		if multiple.mantissa == 0.0 {
+			return *self;
+		}
+
+		(self / multiple).round() * multiple
+	}
+
+	/// Rounds the mantissa to the nearest multiple of the given step, such as 0.5 for 1, 1.5, 2, ..., 9.5,
+	/// keeping the exponent unless the mantissa rounds up to 10. Returns the Decimal unchanged if the step is not positive.
+	pub fn round_clean_mantissa(&self, step: f64) -> Decimal {
+		if step <= 0.0 || self.as_non_finite_string().is_some() {
+			return *self;
+		}
+
+		from_mantissa_exponent((self.mantissa / step).round() * step, self.exponent)
+	}
+
 	/// Truncates the Decimal, if the exponent isn't greater than the maximum significant digits.
 	pub fn trunc(&self) -> Decimal {
 		if self.exponent < 0.0 {
@@ -811,24 +1914,256 @@ impl Decimal {
 		from_mantissa_exponent(1.0 / self.mantissa, -self.exponent)
 	}
 
-	pub fn max(&self, other: &Decimal) -> Decimal {
-		if self > other {
-			*self
-		} else {
-			*other
+	/// Returns the remainder of the Decimal divided by 10^`power`, which has the same sign as the Decimal like [Rem],
+	/// without constructing the power of 10 as a Decimal.
+	/// Decimals too large to have any digits below 10^`power` have a remainder of zero.
+	pub fn mod_pow10(&self, power: i32) -> Decimal {
+		if self.as_non_finite_string().is_some() {
+			return Decimal::NAN;
+		} else if self.mantissa == 0.0 || self.exponent < power as f64 {
+			return *self;
+		}
+
+		let shift = self.exponent - power as f64;
+		if shift >= MAX_SIGNIFICANT_DIGITS as f64 {
+			Decimal::ZERO
+		} else if self.exponent <= NUMBER_EXP_MAX as f64 && power >= -NUMBER_EXP_MAX {
+			Decimal::new(self.to_number() % power_of_10(power))
+		} else {
+			from_mantissa_exponent(self.mantissa * power_of_10(shift as i32) % 1.0, power as f64)
+		}
+	}
+
+	/// Returns the Euclidean quotient of the Decimal and the given Decimal.
+	pub fn div_euclid(&self, other: &Decimal) -> Decimal {
+		self.div_rem(other).0
+	}
+
+	/// Returns the Euclidean remainder of the Decimal and the given Decimal, which is never negative.
+	pub fn rem_euclid(&self, other: &Decimal) -> Decimal {
+		self.div_rem(other).1
+	}
+
+	/// Returns the Euclidean quotient and remainder of the Decimal and the given Decimal, sharing the division.
+	///
+	/// If the quotient has more digits than can be represented, it is effectively `self / other` floored,
+	/// and the remainder is zero as it is lost to precision.
+	/// Both are NaN if the divisor is zero or either Decimal is NaN, like [Rem].
+	pub fn div_rem(&self, other: &Decimal) -> (Decimal, Decimal) {
+		if other.mantissa == 0.0 || self.is_nan() || other.is_nan() {
+			return (Decimal::NAN, Decimal::NAN);
+		}
+
+		let quotient = self / other;
+		if quotient.exponent >= MAX_SIGNIFICANT_DIGITS as f64 || !f64::is_finite(quotient.mantissa) {
+			return (quotient.floor(), Decimal::ZERO);
+		}
+
+		let quotient = if other.mantissa < 0.0 {
+			quotient.ceil()
+		} else {
+			quotient.floor()
+		};
+		let remainder = self - other * quotient;
+
+		// Compensate for floating-point error pushing the remainder out of [0, |other|).
+		let divisor = other.abs();
+		if remainder.mantissa < 0.0 {
+			(quotient - Decimal::new(other.sign()), remainder + divisor)
+		} else if remainder >= divisor {
+			(quotient + Decimal::new(other.sign()), remainder - divisor)
+		} else {
+			(quotient, remainder)
+		}
+	}
+
+	pub fn max(&self, other: &Decimal) -> Decimal {
+		if self > other {
+			*self
+		} else {
+			*other
+		}
+	}
+
+	pub fn min(&self, other: &Decimal) -> Decimal {
+		if self < other {
+			*self
+		} else {
+			*other
+		}
+	}
+
+	pub fn clamp(&self, min: &Decimal, max: &Decimal) -> Decimal {
+		self.max(min).min(max)
+	}
+
+	/// Returns the mantissas of the Decimal and the given Decimal rewritten over their shared, larger exponent,
+	/// followed by that exponent, as [Add] does before adding the mantissas.
+	///
+	/// The mantissa of the smaller operand may become very small, and is zero when the exponents are
+	/// more than [MAX_SIGNIFICANT_DIGITS] apart, as it no longer affects any significant digit of the larger one.
+	pub fn align_exponents(&self, other: &Decimal) -> (f64, f64, f64) {
+		if self.is_nan() || other.is_nan() {
+			return (f64::NAN, f64::NAN, f64::NAN);
+		} else if self.mantissa == 0.0 {
+			return (0.0, other.mantissa, other.exponent);
+		} else if other.mantissa == 0.0 {
+			return (self.mantissa, 0.0, self.exponent);
+		}
+
+		let exponent = self.exponent.max(other.exponent);
+		let align = |decimal: &Decimal| {
+			let difference = decimal.exponent - exponent;
+			if difference < -(MAX_SIGNIFICANT_DIGITS as f64) {
+				0.0
+			} else {
+				decimal.mantissa * power_of_10(difference as i32)
+			}
+		};
+		(align(self), align(other), exponent)
+	}
+
+	/// Returns true if adding the amount to the Decimal would take it from below the threshold to at least it,
+	/// such as when a milestone has just been reached.
+	pub fn crosses_threshold(&self, amount: &Decimal, threshold: &Decimal) -> bool {
+		self < threshold && &(self + amount) >= threshold
+	}
+
+	/// Rounds the mantissa to the given number of significant digits and clamps the result into [min, max],
+	/// so that the value can be sent over the network compactly and every peer agrees on it.
+	/// Clamping happens last so the result is always in range, keeping the precision of a bound it was clamped to.
+	pub fn to_synced(&self, min: &Decimal, max: &Decimal, mantissa_digits: u32) -> Decimal {
+		let quantized = if mantissa_digits >= MAX_SIGNIFICANT_DIGITS || !self.mantissa.is_finite() {
+			*self
+		} else {
+			let scale = 10.0_f64.powi(mantissa_digits.max(1) as i32 - 1);
+			from_mantissa_exponent((self.mantissa * scale).round() / scale, self.exponent)
+		};
+
+		quantized.clamp(min, max)
+	}
+
+	/// Returns the percentage of the total that the Decimal makes up, clamped to [0, 100].
+	/// Returns 0 if the total is zero.
+	pub fn percent_of_total(&self, total: &Decimal) -> f64 {
+		if total.mantissa == 0.0 {
+			return 0.0;
+		}
+
+		((self / total).to_number() * 100.0).clamp(0.0, 100.0)
+	}
+
+	/// Returns the Hermite-smoothed progress of the Decimal from edge0 to edge1, `3t² - 2t³` for the clamped
+	/// linear progress t, which eases in and out of [0, 1]. If the edges are equal, this is a step at the edge.
+	pub fn smoothstep(&self, edge0: &Decimal, edge1: &Decimal) -> f64 {
+		if edge0 == edge1 {
+			return if self < edge0 { 0.0 } else { 1.0 };
+		}
+
+		let t = ((self - edge0) / (edge1 - edge0)).to_number().clamp(0.0, 1.0);
+		t * t * (3.0 - 2.0 * t)
+	}
+
+	/// Returns the absolute difference between the Decimal and the given Decimal relative to their mean,
+	/// or NaN if their sum is zero.
+	pub fn symmetric_percent_diff(&self, other: &Decimal) -> f64 {
+		let sum = self + other;
+		if sum.mantissa == 0.0 {
+			return f64::NAN;
+		}
+
+		((self - other).abs() / (sum / Decimal::new(2.0))).abs().to_number()
+	}
+
+	/// Returns how many standard deviations the Decimal is from the mean, or NaN if the standard deviation is zero.
+	pub fn z_score(&self, mean: &Decimal, std_dev: &Decimal) -> f64 {
+		if std_dev.mantissa == 0.0 {
+			return f64::NAN;
+		}
+
+		((self - mean) / std_dev).to_number()
+	}
+
+	/// Returns the weighted average of the Decimal and the given Decimal, or NaN if the total weight is zero.
+	pub fn blend(&self, other: &Decimal, self_weight: &Decimal, other_weight: &Decimal) -> Decimal {
+		let total_weight = self_weight + other_weight;
+		if total_weight.mantissa == 0.0 {
+			return Decimal::NAN;
+		}
+
+		(self * self_weight + other * other_weight) / total_weight
+	}
+
+	/// Combines two estimates by inverse-variance weighting, `(a/var_a + b/var_b) / (1/var_a + 1/var_b)`.
+	/// An estimate with zero variance is exact and dominates, and two exact estimates are averaged.
+	pub fn inverse_variance_combine(a: &Decimal, var_a: &Decimal, b: &Decimal, var_b: &Decimal) -> Decimal {
+		match (var_a.mantissa == 0.0, var_b.mantissa == 0.0) {
+			(true, true) => (a + b) / Decimal::new(2.0),
+			(true, false) => *a,
+			(false, true) => *b,
+			(false, false) => a.blend(b, &var_a.recip(), &var_b.recip()),
+		}
+	}
+
+	/// Evaluates a piecewise-linear curve of `(threshold, multiplier)` breakpoints at the Decimal,
+	/// interpolating linearly between breakpoints and extrapolating flat beyond the ends.
+	/// The breakpoints are sorted by threshold first, and NaN is returned if there are none.
+	pub fn piecewise_eval(&self, breakpoints: &[(Decimal, Decimal)]) -> Decimal {
+		let mut breakpoints = breakpoints.to_vec();
+		breakpoints.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+		let index = breakpoints.partition_point(|(threshold, _)| threshold <= self);
+		match (
+			index.checked_sub(1).map(|i| breakpoints[i]),
+			breakpoints.get(index).copied(),
+		) {
+			(None, None) => Decimal::NAN,
+			(Some((_, multiplier)), None) | (None, Some((_, multiplier))) => multiplier,
+			(Some((start, start_multiplier)), Some((end, end_multiplier))) => {
+				let t = (self - start) / (end - start);
+				start_multiplier + (end_multiplier - start_multiplier) * t
+			}
+		}
+	}
+
+	/// Returns the ordering between the Decimal and the given Decimal, where NaN is greater than every other value.
+	pub fn total_cmp(&self, other: &Decimal) -> Ordering {
+		match (self.is_nan(), other.is_nan()) {
+			(true, true) => Equal,
+			(true, false) => Greater,
+			(false, true) => Less,
+			(false, false) => self.partial_cmp(other).unwrap_or(Equal),
 		}
 	}
 
-	pub fn min(&self, other: &Decimal) -> Decimal {
-		if self < other {
-			*self
+	/// Compares only the signs and exponents of the two Decimals, ignoring their mantissas.
+	///
+	/// This is not a total order on values, as Decimals with the same exponent compare as equal,
+	/// but it is cheaper than a full comparison and can be used as a pre-filter before one.
+	/// NaN exponents compare as equal.
+	pub fn cmp_exponent(&self, other: &Decimal) -> Ordering {
+		let sign = self.sign();
+		let other_sign = other.sign();
+		if sign == 0.0 || sign != other_sign {
+			return sign.partial_cmp(&other_sign).unwrap_or(Equal);
+		}
+
+		let ordering = self.exponent.partial_cmp(&other.exponent).unwrap_or(Equal);
+		if sign < 0.0 {
+			ordering.reverse()
 		} else {
-			*other
+			ordering
 		}
 	}
 
-	pub fn clamp(&self, min: &Decimal, max: &Decimal) -> Decimal {
-		self.max(min).min(max)
+	/// Returns true if the Decimal lies within `[low, high]`, or `(low, high)` if not inclusive.
+	/// NaN is never in range.
+	pub fn in_range(&self, low: &Decimal, high: &Decimal, inclusive: bool) -> bool {
+		if inclusive {
+			self >= low && self <= high
+		} else {
+			self > low && self < high
+		}
 	}
 
 	pub fn cmp_tolerance(&self, decimal: &Decimal, tolerance: &Decimal) -> Option<Ordering> {
@@ -844,7 +2179,22 @@ impl Decimal {
 	/// larger number than (larger number) * 1e-9 will be considered equal.
 	pub fn eq_tolerance(&self, decimal: &Decimal, tolerance: &Decimal) -> bool {
 		// return abs(a-b) <= tolerance * max(abs(a), abs(b))
-		(self - decimal).abs().le(&self.abs().max(&(decimal.abs() * tolerance)))
+		(self - decimal).abs().le(&(self.abs().max(&decimal.abs()) * tolerance))
+	}
+
+	/// Epsilon is an absolute tolerance, so any number within epsilon of the other number will be considered equal.
+	pub fn eq_absolute(&self, decimal: &Decimal, epsilon: &Decimal) -> bool {
+		(self - decimal).abs() <= *epsilon
+	}
+
+	/// Returns true if the Decimal is within epsilon of zero.
+	pub fn is_approx_zero(&self, epsilon: &Decimal) -> bool {
+		self.eq_absolute(&Decimal::ZERO, epsilon)
+	}
+
+	/// Returns true if the Decimal is within epsilon of one.
+	pub fn is_approx_one(&self, epsilon: &Decimal) -> bool {
+		self.eq_absolute(&Decimal::ONE, epsilon)
 	}
 
 	pub fn neq_tolerance(&self, decimal: &Decimal, tolerance: &Decimal) -> bool {
@@ -873,6 +2223,66 @@ impl Decimal {
 		self.exponent + self.mantissa.abs().log10()
 	}
 
+	/// Returns the exponent of the absolute value of the Decimal as a continuous magnitude, which is its log10.
+	///
+	/// Unlike the stored exponent, which is the floor of this once normalized, it includes the mantissa,
+	/// so `5e7` has a fractional exponent of about 7.699 rather than 7.
+	pub fn fractional_exponent(&self) -> f64 {
+		self.abs_log10()
+	}
+
+	/// Returns how far through its power of 10 the absolute value of the Decimal is on a logarithmic scale,
+	/// which is the fractional part of its log10 in `[0, 1)`. Returns 0 for zero.
+	pub fn decade_fraction(&self) -> f64 {
+		if self.mantissa == 0.0 {
+			return 0.0;
+		}
+
+		self.mantissa.abs().log10()
+	}
+
+	/// Returns a hue in degrees, in `[0, 360)`, which goes once around the color wheel every `period` orders of magnitude,
+	/// for tinting numbers by their scale. Returns 0 (red) for Decimals which are not positive or a period which is not.
+	pub fn magnitude_hue(&self, period: f64) -> f32 {
+		if self.mantissa <= 0.0 || self.is_nan() || period <= 0.0 || !period.is_finite() {
+			return 0.0;
+		}
+
+		// Reducing the exponent first keeps the fraction precise for huge exponents.
+		let turns = ((self.exponent % period + self.mantissa.log10()) / period).rem_euclid(1.0);
+		let hue = (turns * 360.0) as f32;
+		if hue >= 360.0 {
+			0.0
+		} else {
+			hue
+		}
+	}
+
+	/// Returns the number of orders of magnitude separating the Decimal from the given Decimal,
+	/// or NaN if either of them is not positive.
+	pub fn magnitude_ratio(&self, other: &Decimal) -> f64 {
+		if self.mantissa <= 0.0 || other.mantissa <= 0.0 {
+			return f64::NAN;
+		}
+
+		self.log10() - other.log10()
+	}
+
+	/// Returns how far the Decimal is from `from` to `to` on a logarithmic scale, clamped to [0, 1].
+	/// Returns NaN if either bound is not positive or `from` is not less than `to`.
+	pub fn log_progress(&self, from: &Decimal, to: &Decimal) -> f64 {
+		if from.mantissa <= 0.0 || to.mantissa <= 0.0 || from >= to {
+			return f64::NAN;
+		} else if self <= from {
+			return 0.0;
+		} else if self >= to {
+			return 1.0;
+		}
+
+		let from_log = from.log10();
+		((self.log10() - from_log) / (to.log10() - from_log)).clamp(0.0, 1.0)
+	}
+
 	pub fn p_log10(&self) -> f64 {
 		if self.mantissa <= 0.0 || self.exponent < 0.0 {
 			0.0
@@ -890,20 +2300,77 @@ impl Decimal {
 		self.log(base)
 	}
 
+	/// Returns the logarithm of the Decimal with a Decimal base, for bases too large to fit in an f64.
+	/// Returns NaN if the base is not positive or is equal to one.
+	pub fn log_of_base(&self, base: &Decimal) -> f64 {
+		if base.mantissa <= 0.0 || *base == Decimal::ONE {
+			return f64::NAN;
+		}
+
+		self.log10() / base.log10()
+	}
+
 	pub fn log2(&self) -> f64 {
 		LOG2_10 * self.log10()
 	}
 
+	/// Returns the bits of information in observing an event with the Decimal as its probability, which is `-log2`.
+	/// Impossible events, with non-positive probabilities, have infinite surprisal.
+	pub fn surprisal(&self) -> f64 {
+		if self.is_nan() {
+			f64::NAN
+		} else if self.mantissa <= 0.0 {
+			f64::INFINITY
+		} else {
+			// Taking log2 of the mantissa directly is more accurate than scaling its log10.
+			-(self.exponent * LOG2_10 + self.mantissa.log2())
+		}
+	}
+
+	/// Returns how many doublings it takes to get from the Decimal to the target,
+	/// which is negative if the target is smaller, or NaN if either of them is not positive.
+	pub fn doublings_until(&self, target: &Decimal) -> f64 {
+		if self.mantissa <= 0.0 || target.mantissa <= 0.0 {
+			return f64::NAN;
+		}
+
+		target.log2() - self.log2()
+	}
+
 	pub fn ln(&self) -> f64 {
 		LN_10 * self.log10()
 	}
 
+	/// Returns the natural logarithm of the Decimal as a Decimal,
+	/// which does not overflow for exponents near the `full-range` limit.
+	pub fn ln_full(&self) -> Decimal {
+		Decimal::new(self.log10()) * Decimal::new(LN_10)
+	}
+
 	/// Raises the Decimal to the power of the given Decimal.
+	///
+	/// A negative Decimal raised to a non-integer power is NaN. Otherwise, the result is found by the first of:
+	/// - the fast track, when the exponent times the power is a safe integer
+	///   and the mantissa to the power fits in an f64, such as small integer powers;
+	/// - the residue path, when 10 to the power times the log10 of the mantissa, plus the fractional part of
+	///   the exponent times the power, fits in an f64, such as non-integer powers of positive Decimals;
+	/// - the fallback path through [Decimal::pow10] of the power times the log10 of the absolute value, such as for
+	///   negative Decimals whose mantissa to the power overflows, where the sign comes from the parity of the power.
 	pub fn pow(&self, decimal: &Decimal) -> Decimal {
+		self.pow_unfinished(decimal).finish_arithmetic()
+	}
+
+	/// Raises the Decimal to the power of the given Decimal without the post-processing of [Decimal::finish_arithmetic].
+	fn pow_unfinished(&self, decimal: &Decimal) -> Decimal {
 		if self.mantissa == 0.0 {
 			return *self;
 		}
 
+		let number = decimal.to_number();
+		if self.mantissa < 0.0 && number.fract() != 0.0 {
+			return Decimal::NAN;
+		}
+
 		//  UN-SAFETY: Accuracy not guaranteed beyond ~9-11 decimal places.
 		//  TODO: Decimal.pow(new Decimal(0.5), 0); or Decimal.pow(new Decimal(1), -1);
 		//	makes an exponent of -0! Is a negative zero ever a problem?
@@ -916,11 +2383,10 @@ impl Decimal {
 
 		// Fast track: If (this.e*value) is an integer and mantissa^value
 		// fits in a Number, we can do a very fast method.
-		let number = decimal.to_number();
 		let temp = self.exponent * number;
 
 		let mut new_mantissa;
-		if temp < MAX_SAFE_INTEGER {
+		if temp.fract() == 0.0 && temp.abs() <= MAX_SAFE_INTEGER {
 			// Same speed and usually more accurate.
 			new_mantissa = self.mantissa.powf(number);
 
@@ -935,11 +2401,37 @@ impl Decimal {
 
 		if f64::is_finite(new_mantissa) && new_mantissa != 0.0 {
 			//  return Decimal.exp(value*this.ln());
-			//  UN-SAFETY: This should return NaN when mantissa is negative and value is non-integer.
 			return from_mantissa_exponent(new_mantissa, new_exponent);
 		}
 
-		let result = Decimal::pow10(number * self.abs_log10());
+		let result = Decimal::pow10_unfinished(number * self.abs_log10());
+
+		// The power is an integer here, as non-integer powers of negative Decimals have already returned NaN.
+		if self.sign() == -1.0 && number % 2.0 != 0.0 {
+			result.neg()
+		} else {
+			result
+		}
+	}
+
+	/// Raises the Decimal to the power of the given Decimal, always going through the logarithm so the result is
+	/// non-decreasing in the power for bases above 1 and non-increasing for bases between 0 and 1.
+	///
+	/// [Decimal::pow] switches between methods depending on the power, which can make the result jump slightly
+	/// at the boundaries; this is a little slower, but is safe for searches that assume monotonicity.
+	pub fn pow_monotonic(&self, decimal: &Decimal) -> Decimal {
+		if self.mantissa == 0.0 {
+			return *self;
+		}
+
+		let number = decimal.to_number();
+		let power = number * self.abs_log10();
+		let exponent = power.floor();
+		// Rounding can push the mantissa up to 10, which would overtake the start of the next power of 10.
+		let mantissa = 10.0_f64
+			.powf(power - exponent)
+			.min(f64::from_bits(10.0_f64.to_bits() - 1));
+		let result = from_mantissa_exponent_no_normalize(mantissa, exponent).finish_arithmetic();
 
 		if self.sign() == -1.0 {
 			match (number % 2.0).abs() {
@@ -956,25 +2448,95 @@ impl Decimal {
 		decimal.pow(self)
 	}
 
+	/// Saturates the unfinished result of an arithmetic operation to the infinity sentinel if it has overflowed
+	/// past the exponent limit, returning whether it did, or else applies [Decimal::finish_arithmetic] to it.
+	/// Overflow is checked first, so that it is still reported with the `nan-on-overflow` feature.
+	fn finish_checked(self) -> (Decimal, bool) {
+		if self.exponent > EXP_LIMIT && self.mantissa != 0.0 {
+			(
+				Decimal {
+					mantissa: self.mantissa.signum(),
+					exponent: EXP_LIMIT,
+				},
+				true,
+			)
+		} else {
+			(self.finish_arithmetic(), false)
+		}
+	}
+
+	/// Returns the relative error of the Decimal against the exact result of an operation which fits in an f64.
+	#[cfg(any(test, feature = "testing"))]
+	pub fn relative_error_vs(&self, exact: f64) -> f64 {
+		((self.to_number() - exact) / exact).abs()
+	}
+
+	/// Returns how many leading significant digits of the Decimal agree with a more precise reference value,
+	/// up to the maximum significant digits.
+	#[cfg(any(test, feature = "testing"))]
+	pub fn effective_sig_figs(&self, reference: &Decimal) -> u32 {
+		if self == reference {
+			return MAX_SIGNIFICANT_DIGITS;
+		} else if reference.mantissa == 0.0 || self.is_nan() || reference.is_nan() {
+			return 0;
+		}
+
+		let error = (self - reference).abs_log10() - reference.abs_log10();
+		(-error).floor().clamp(0.0, MAX_SIGNIFICANT_DIGITS as f64) as u32
+	}
+
+	/// Subtracts the given Decimal, returning the difference and the number of significant digits lost to cancellation,
+	/// which is how many orders of magnitude the difference dropped below the larger operand.
+	/// All significant digits are lost if the operands are equal.
+	pub fn sub_with_cancellation(&self, other: &Decimal) -> (Decimal, f64) {
+		let difference = self - other;
+		if self.mantissa == 0.0 || other.mantissa == 0.0 {
+			(difference, 0.0)
+		} else if difference.mantissa == 0.0 {
+			(difference, MAX_SIGNIFICANT_DIGITS as f64)
+		} else {
+			let lost = self.abs().max(&other.abs()).log10() - difference.abs().log10();
+			(difference, lost.clamp(0.0, MAX_SIGNIFICANT_DIGITS as f64))
+		}
+	}
+
+	/// Adds the given Decimal, returning the sum and whether it saturated past the exponent limit.
+	pub fn add_checked_overflow(&self, other: &Decimal) -> (Decimal, bool) {
+		self.add_unfinished(*other).finish_checked()
+	}
+
+	/// Multiplies by the given Decimal, returning the product and whether it saturated past the exponent limit.
+	pub fn mul_checked_overflow(&self, other: &Decimal) -> (Decimal, bool) {
+		self.mul_unfinished(*other).finish_checked()
+	}
+
+	/// Raises the Decimal to the power of the given Decimal,
+	/// returning the result and whether it saturated past the exponent limit.
+	pub fn pow_checked_overflow(&self, other: &Decimal) -> (Decimal, bool) {
+		self.pow_unfinished(other).finish_checked()
+	}
+
 	pub fn factorial(&self) -> Decimal {
 		//  Using Stirling's Approximation.
 		//  https://en.wikipedia.org/wiki/Stirling%27s_approximation#Versions_suitable_for_calculators
+		//  This is evaluated in log10 space, as the intermediate power overflows an f64 past 170!.
 		let n = self.to_number() + 1.0;
-		Decimal::new(n / E * (n * f64::sinh(1.0 / n) + 1.0 / (810.0 * n.powi(6)))).pow(&Decimal::new(n))
-			* Decimal::new(f64::sqrt(2.0 * PI / n))
+		let log10 = n * (n / E * f64::sqrt(n * f64::sinh(1.0 / n) + 1.0 / (810.0 * n.powi(6)))).log10()
+			+ f64::log10(2.0 * PI / n) / 2.0;
+		Decimal::pow10(log10)
 	}
 
 	pub fn exp(&self) -> Decimal {
 		// Fast track: if -706 < this < 709, we can use regular exp.
 		let number = self.to_number();
 		if -706.0 < number && number < 709.0 {
-			return Decimal::new(f64::exp(number));
+			return Decimal::new(f64::exp(number)).finish_arithmetic();
 		}
 		Decimal::E.pow(self)
 	}
 
 	pub fn sqr(&self) -> Decimal {
-		from_mantissa_exponent(self.mantissa.powi(2), self.exponent * 2.0)
+		from_mantissa_exponent(self.mantissa.powi(2), self.exponent * 2.0).finish_arithmetic()
 	}
 
 	pub fn sqrt(&self) -> Decimal {
@@ -985,13 +2547,49 @@ impl Decimal {
 			return from_mantissa_exponent(
 				f64::sqrt(self.mantissa) * 3.16227766016838,
 				(self.exponent / 2.0).floor(),
-			);
+			)
+			.finish_arithmetic();
+		}
+		from_mantissa_exponent(f64::sqrt(self.mantissa), (self.exponent / 2.0).floor()).finish_arithmetic()
+	}
+
+	/// Returns the nth root of the Decimal.
+	pub fn root(&self, n: f64) -> Decimal {
+		self.pow(&Decimal::new(1.0 / n))
+	}
+
+	/// Returns the principal nth root of the Decimal for an integer n,
+	/// which is NaN for even roots of negative Decimals and for n = 0.
+	// u32::is_multiple_of would need Rust 1.87.
+	#[allow(clippy::manual_is_multiple_of)]
+	pub fn principal_root(&self, n: u32) -> Decimal {
+		if n == 0 || (self.mantissa < 0.0 && n % 2 == 0) {
+			return Decimal::NAN;
+		} else if self.mantissa == 0.0 || n == 1 {
+			return *self;
+		}
+
+		// Shift the part of the exponent not divisible by n into the mantissa, like sqrt and cbrt do.
+		let n = n as f64;
+		let new_exponent = (self.exponent / n).floor();
+		let remainder = self.exponent - new_exponent * n;
+		let new_mantissa = 10.0_f64.powf((self.mantissa.abs().log10() + remainder) / n);
+
+		from_mantissa_exponent(self.mantissa.signum() * new_mantissa, new_exponent)
+	}
+
+	/// Returns the multiplicative rate per tick which takes `start` to `end` over the given number of ticks,
+	/// or NaN if either of them is not positive.
+	pub fn growth_rate(start: &Decimal, end: &Decimal, ticks: f64) -> Decimal {
+		if start.mantissa <= 0.0 || end.mantissa <= 0.0 {
+			return Decimal::NAN;
 		}
-		from_mantissa_exponent(f64::sqrt(self.mantissa), (self.exponent / 2.0).floor())
+
+		(end / start).root(ticks)
 	}
 
 	pub fn cube(&self) -> Decimal {
-		from_mantissa_exponent(self.mantissa.powi(3), self.exponent * 3.0)
+		from_mantissa_exponent(self.mantissa.powi(3), self.exponent * 3.0).finish_arithmetic()
 	}
 
 	pub fn cbrt(&self) -> Decimal {
@@ -1007,15 +2605,17 @@ impl Decimal {
 		let remainder = (self.exponent % 3.0) as i32;
 
 		if remainder == 1 || remainder == -2 {
-			return from_mantissa_exponent(new_mantissa * 2.154_434_690_031_884, (self.exponent / 3.0).floor());
+			return from_mantissa_exponent(new_mantissa * 2.154_434_690_031_884, (self.exponent / 3.0).floor())
+				.finish_arithmetic();
 		}
 
 		if remainder != 0 {
 			// remainder != 0 at this point means 'remainder == 2 || remainder == -1'
-			return from_mantissa_exponent(new_mantissa * 4.641_588_833_612_779, (self.exponent / 3.0).floor());
+			return from_mantissa_exponent(new_mantissa * 4.641_588_833_612_779, (self.exponent / 3.0).floor())
+				.finish_arithmetic();
 		}
 
-		from_mantissa_exponent(new_mantissa, (self.exponent / 3.0).floor())
+		from_mantissa_exponent(new_mantissa, (self.exponent / 3.0).floor()).finish_arithmetic()
 	}
 
 	// Some hyperbolic trigonometry functions that happen to be easy
@@ -1029,6 +2629,27 @@ impl Decimal {
 		self.sinh() / self.cosh()
 	}
 
+	/// Smoothly limits the magnitude of the Decimal to the ceiling using a tanh curve,
+	/// which is close to the Decimal well below the ceiling and approaches the ceiling above it.
+	/// Greater softness rounds off the transition more, while a softness of 0 is a hard clamp.
+	pub fn smooth_clamp(&self, ceiling: &Decimal, softness: f64) -> Decimal {
+		if softness <= 0.0 {
+			return self.clamp(&ceiling.neg(), ceiling);
+		}
+
+		let ratio = (self / ceiling).abs().pow(&Decimal::new(1.0 / softness));
+		// Outside of this range, tanh is indistinguishable from its asymptotes.
+		let curve = if ratio.exponent < -8.0 {
+			ratio
+		} else if ratio.exponent > 1.0 {
+			Decimal::ONE
+		} else {
+			ratio.tanh()
+		};
+
+		curve.pow(&Decimal::new(softness)) * ceiling * Decimal::new(self.sign())
+	}
+
 	pub fn asinh(&self) -> f64 {
 		(self + (self.sqr() + Decimal::new(1.0)).sqrt()).ln()
 	}
@@ -1094,6 +2715,12 @@ pub fn afford_geometric_series(
 	.floor()
 }
 
+/// What is the price of the next item after buying (buying) more items if you already have owned,
+/// the initial price is priceStart and it multiplies by priceRatio each purchase?
+pub fn price_after(price_start: &Decimal, price_ratio: &Decimal, owned: &Decimal, buying: &Decimal) -> Decimal {
+	price_start * price_ratio.pow(&(owned + buying))
+}
+
 /// How much resource would it cost to buy (numItems) items if you already have currentOwned,
 /// the initial price is priceStart and it multiplies by priceRatio each purchase?
 pub fn sum_geometric_series(
@@ -1103,6 +2730,56 @@ pub fn sum_geometric_series(
 		/ (Decimal::new(1.0) - price_ratio)
 }
 
+/// Returns the running total cost after buying 1, 2, ... items with exponentially increasing cost,
+/// stopping once the total exceeds the available resources or `max` items have been bought.
+pub fn affordable_costs(
+	resources: &Decimal, price_start: &Decimal, price_ratio: &Decimal, owned: &Decimal, max: usize,
+) -> Vec<Decimal> {
+	let mut costs = Vec::new();
+	let mut price = price_start * price_ratio.pow(owned);
+	let mut total = Decimal::ZERO;
+
+	while costs.len() < max {
+		total += price;
+		if total > *resources {
+			break;
+		}
+
+		costs.push(total);
+		price *= price_ratio;
+	}
+
+	costs
+}
+
+/// Like [sum_geometric_series], but robust against huge numbers of items.
+///
+/// When `price_ratio ^ num_items` dominates, the sum is computed in logarithmic space,
+/// and a sum past the exponent limit saturates to the infinity sentinel [Decimal::MAX_VALUE]
+/// rather than overflowing into an invalid exponent.
+/// A price ratio of exactly 1 is treated as a constant price.
+pub fn sum_geometric_series_saturating(
+	num_items: &Decimal, price_start: &Decimal, price_ratio: &Decimal, current_owned: &Decimal,
+) -> Decimal {
+	if *price_ratio == Decimal::ONE {
+		return price_start * num_items;
+	}
+
+	let growth = num_items.to_number() * price_ratio.log10();
+	if growth.abs() <= MAX_SIGNIFICANT_DIGITS as f64 || price_ratio < &Decimal::ONE {
+		return sum_geometric_series(num_items, price_start, price_ratio, current_owned);
+	}
+
+	// 1 - r^n is indistinguishable from -r^n here, so the sum is start * r^(owned + n) / (r - 1).
+	let log10 = price_start.log10() + current_owned.to_number() * price_ratio.log10() + growth
+		- (price_ratio - Decimal::ONE).log10();
+	if !log10.is_finite() || log10 >= EXP_LIMIT {
+		return Decimal::MAX_VALUE;
+	}
+
+	Decimal::pow10(log10)
+}
+
 /// If you're willing to spend 'resourcesAvailable' and want to buy something with additively
 /// increasing cost each purchase (start at priceStart, add by priceAdd, already own currentOwned),
 /// how much of it can you buy?
@@ -1131,6 +2808,33 @@ pub fn sum_arithmetic_series(
 		* price_add
 }
 
+/// What is the price of the nth purchase (counting from zero), if the price is base plus additive per purchase,
+/// multiplied by ratio per purchase? That is, `(base + additive * n) * ratio^n`.
+pub fn hybrid_cost(base: &Decimal, additive: &Decimal, ratio: &Decimal, n: &Decimal) -> Decimal {
+	(base + additive * n) * ratio.pow(n)
+}
+
+/// How much resource would it cost to make the first n purchases priced by [hybrid_cost]?
+/// Uses the closed forms of the geometric series and its derivative, or of the arithmetic series if ratio is 1.
+/// If ratio is 0, only the first purchase costs anything, so the sum is base once any purchase is made.
+pub fn sum_hybrid_cost(base: &Decimal, additive: &Decimal, ratio: &Decimal, n: &Decimal) -> Decimal {
+	if ratio.mantissa == 0.0 {
+		return if n.mantissa > 0.0 { *base } else { Decimal::ZERO };
+	} else if ratio == &Decimal::ONE {
+		// base * n + additive * n(n - 1)/2
+		return base * n + additive * n * (n - Decimal::ONE) / Decimal::new(2.0);
+	}
+
+	// Sum of r^k is (1 - r^n)/(1 - r), and sum of k*r^k is r(1 - n*r^(n-1) + (n-1)*r^n)/(1 - r)^2.
+	let one_minus_ratio = Decimal::ONE - ratio;
+	let ratio_n = ratio.pow(n);
+	let geometric = (Decimal::ONE - ratio_n) / one_minus_ratio;
+	let weighted = ratio * (Decimal::ONE - n * ratio_n / ratio + (n - Decimal::ONE) * ratio_n)
+		/ (one_minus_ratio * one_minus_ratio);
+
+	base * geometric + additive * weighted
+}
+
 /// When comparing two purchases that cost (resource) and increase your resource/sec by (deltaRpS),
 /// the lowest efficiency score is the better one to purchase.
 ///
@@ -1139,3 +2843,379 @@ pub fn sum_arithmetic_series(
 pub fn efficiency_of_purchase(cost: &Decimal, current_rp_s: &Decimal, delta_rp_s: &Decimal) -> Decimal {
 	cost / (current_rp_s + (cost / delta_rp_s))
 }
+
+/// How much prestige currency is gained from a total of resources, given the requirement to gain any?
+/// The gain is `floor((total / requirement) ^ exponent)`, such as a square root with an exponent of 0.5,
+/// and is zero if the total is less than the requirement.
+pub fn prestige_gain(total: &Decimal, requirement: &Decimal, exponent: f64) -> Decimal {
+	if total < requirement {
+		return Decimal::ZERO;
+	}
+
+	(total / requirement).pow(&Decimal::new(exponent)).floor()
+}
+
+/// How long would it take for the increase in resources per second from a purchase to pay back its cost?
+/// Returns `Decimal::MAX_VALUE` if the purchase does not increase the rate, as it never pays for itself.
+pub fn break_even_time(cost: &Decimal, delta_rp_s: &Decimal) -> Decimal {
+	if delta_rp_s.mantissa == 0.0 {
+		return Decimal::MAX_VALUE;
+	}
+
+	cost / delta_rp_s
+}
+
+/// How long would it take to go from current to target, gaining ratePerSec each second?
+/// Returns zero if the target is already reached, or `Decimal::MAX_VALUE` if the rate is not positive.
+pub fn time_to_reach(current: &Decimal, target: &Decimal, rate_per_sec: &Decimal) -> Decimal {
+	if current >= target {
+		return Decimal::ZERO;
+	} else if rate_per_sec.mantissa <= 0.0 {
+		return Decimal::MAX_VALUE;
+	}
+
+	(target - current) / rate_per_sec
+}
+
+/// How quickly did a value change between two samples taken dt seconds apart?
+/// Returns `Decimal::NAN` if dt is zero, as the rate is undefined.
+pub fn rate_between(earlier: &Decimal, later: &Decimal, dt: f64) -> Decimal {
+	if dt == 0.0 {
+		return Decimal::NAN;
+	}
+
+	(later - earlier) / Decimal::new(dt)
+}
+
+/// What is the combined rate per second of sources which each give a rate per unit of the given number of seconds,
+/// such as 60 for a rate per minute? Returns NaN if any unit is zero seconds long.
+pub fn total_per_second(rates: &[(Decimal, f64)]) -> Decimal {
+	if rates.iter().any(|(_, seconds_per_unit)| *seconds_per_unit == 0.0) {
+		return Decimal::NAN;
+	}
+
+	rates
+		.iter()
+		.map(|(rate, seconds_per_unit)| rate / Decimal::new(*seconds_per_unit))
+		.sum()
+}
+
+/// How much would principal grow to after compounding by ratePerSec each second for the given number of seconds?
+pub fn compound(principal: &Decimal, rate_per_sec: &Decimal, seconds: &Decimal) -> Decimal {
+	principal * (Decimal::ONE + rate_per_sec).pow(seconds)
+}
+
+/// How many resources are gained over the given number of ticks, if income starts at initialRate per tick
+/// and is multiplied by growth every tick? This is the sum of `initial_rate * growth^t` for t in `0..ticks`.
+pub fn accumulate_geometric(initial_rate: &Decimal, growth: &Decimal, ticks: &Decimal) -> Decimal {
+	if growth == &Decimal::ONE {
+		return initial_rate * ticks;
+	}
+
+	sum_geometric_series(ticks, initial_rate, growth, &Decimal::ZERO)
+}
+
+/// How long would it take to go from current to target, compounding by ratePerSec each second?
+/// This is the inverse of [compound]. Returns zero if the target is already reached,
+/// or `Decimal::MAX_VALUE` if the rate or current is not positive, as the target is never reached.
+pub fn time_to_reach_exponential(current: &Decimal, target: &Decimal, rate_per_sec: &Decimal) -> Decimal {
+	if current >= target {
+		return Decimal::ZERO;
+	} else if rate_per_sec.mantissa <= 0.0 || current.mantissa <= 0.0 {
+		return Decimal::MAX_VALUE;
+	}
+
+	// ln_1p keeps precision for small rates, where 1 + rate would round to 1,
+	// but large rates may not fit in an f64, so they are added as Decimals.
+	let growth_log10 = if rate_per_sec.exponent < 0.0 {
+		rate_per_sec.to_number().ln_1p() / LN_10
+	} else {
+		(Decimal::ONE + rate_per_sec).log10()
+	};
+	Decimal::new((target / current).log10() / growth_log10)
+}
+
+/// How many purchases, each multiplying production by perPurchase, does it take to reach a total multiplier of targetTotal?
+/// Returns `Decimal::MAX_VALUE` if perPurchase is not greater than 1, as the target is never reached.
+pub fn purchases_for_multiplier(per_purchase: &Decimal, target_total: &Decimal) -> Decimal {
+	if target_total <= &Decimal::ONE {
+		return Decimal::ZERO;
+	} else if per_purchase <= &Decimal::ONE {
+		return Decimal::MAX_VALUE;
+	}
+
+	let purchases = target_total.log10() / per_purchase.log10();
+	// Don't let floating-point error in the logarithms require an extra purchase.
+	let rounded = purchases.round();
+	if (rounded - purchases).abs() < purchases * 1e-10 {
+		Decimal::new(rounded)
+	} else {
+		Decimal::new(purchases.ceil())
+	}
+}
+
+/// Returns the indices of the `(cost, current_rp_s, delta_rp_s)` options sorted by their efficiency of purchase,
+/// best first. Options which do not increase the rate are skipped.
+pub fn rank_purchases(options: &[(Decimal, Decimal, Decimal)]) -> Vec<usize> {
+	let mut ranked: Vec<(usize, Decimal)> = options
+		.iter()
+		.enumerate()
+		.filter(|(_, (_, _, delta_rp_s))| delta_rp_s.mantissa > 0.0)
+		.map(|(index, (cost, current_rp_s, delta_rp_s))| {
+			(index, efficiency_of_purchase(cost, current_rp_s, delta_rp_s))
+		})
+		.collect();
+	ranked.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+	ranked.into_iter().map(|(index, _)| index).collect()
+}
+
+/// Bisects between low and high on a logarithmic scale for the given number of iterations, returning the largest
+/// value found for which the predicate is still true. The predicate should be monotonic, true at low and false at high,
+/// and both bounds should be positive.
+pub fn bisect<F: Fn(&Decimal) -> bool>(low: &Decimal, high: &Decimal, pred: F, iterations: u32) -> Decimal {
+	if pred(high) {
+		return *high;
+	}
+
+	let (mut low, mut high) = (*low, *high);
+	for _ in 0..iterations {
+		let middle = Decimal::log_lerp(&low, &high, 0.5);
+		if pred(&middle) {
+			low = middle;
+		} else {
+			high = middle;
+		}
+	}
+
+	low
+}
+
+/// Encodes the values as the first value followed by the differences between successive values.
+///
+/// Decoding with [delta_decode] is only as accurate as the subtractions, so digits are lost
+/// when a difference is many orders of magnitude smaller than the values.
+pub fn delta_encode(values: &[Decimal]) -> Vec<Decimal> {
+	values
+		.iter()
+		.scan(Decimal::ZERO, |previous, value| {
+			let delta = value - *previous;
+			*previous = *value;
+			Some(delta)
+		})
+		.collect()
+}
+
+/// Decodes the values encoded by [delta_encode].
+pub fn delta_decode(deltas: &[Decimal]) -> Vec<Decimal> {
+	cumulative_sum(deltas)
+}
+
+/// Rounds the Decimal to a "nice" number of 1, 2 or 5 times a power of 10, to the nearest one if round is true,
+/// or else to the smallest one that is at least the Decimal.
+fn nice_number(decimal: &Decimal, round: bool) -> Decimal {
+	let fraction = decimal.mantissa;
+	let nice = if round {
+		match fraction {
+			f if f < 1.5 => 1.0,
+			f if f < 3.0 => 2.0,
+			f if f < 7.0 => 5.0,
+			_ => 10.0,
+		}
+	} else {
+		match fraction {
+			f if f <= 1.0 => 1.0,
+			f if f <= 2.0 => 2.0,
+			f if f <= 5.0 => 5.0,
+			_ => 10.0,
+		}
+	};
+	from_mantissa_exponent(nice, decimal.exponent)
+}
+
+/// Returns pleasant bounds and a tick step for a chart axis covering min to max with about targetTicks ticks,
+/// as `(nice_min, nice_max, tick_step)`, where the step is 1, 2 or 5 times a power of 10.
+/// If min and max are equal, the axis is widened around them. Returns NaNs if either is NaN.
+pub fn nice_axis(min: &Decimal, max: &Decimal, target_ticks: u32) -> (Decimal, Decimal, Decimal) {
+	if min.is_nan() || max.is_nan() {
+		return (Decimal::NAN, Decimal::NAN, Decimal::NAN);
+	}
+
+	let (mut low, mut high) = if min <= max { (*min, *max) } else { (*max, *min) };
+	if low == high {
+		let padding = if low.mantissa == 0.0 {
+			Decimal::ONE
+		} else {
+			from_mantissa_exponent_no_normalize(1.0, low.exponent)
+		};
+		low -= padding;
+		high += padding;
+	}
+
+	let range = nice_number(&(high - low), false);
+	let step = nice_number(&(range / Decimal::new((target_ticks.max(2) - 1) as f64)), true);
+	((low / step).floor() * step, (high / step).ceil() * step, step)
+}
+
+/// Returns an exponent offset such that multiplying each of the values by 10^-offset centres their product around 1,
+/// so that the product can be taken as `f64`s without overflowing and rescaled by 10^(offset * n) at the end.
+/// The offset is the mean of the log10 of the nonzero values, rounded to an integer.
+pub fn common_scale(values: &[Decimal]) -> f64 {
+	let logs: Vec<f64> = values
+		.iter()
+		.filter(|value| value.mantissa != 0.0)
+		.map(Decimal::abs_log10)
+		.collect();
+	if logs.is_empty() {
+		return 0.0;
+	}
+
+	(logs.iter().sum::<f64>() / logs.len() as f64).round()
+}
+
+/// Returns the cumulative thresholds of the weights for weighted random selection with [select_index].
+pub fn cumulative_weights(weights: &[Decimal]) -> Vec<Decimal> {
+	cumulative_sum(weights)
+}
+
+/// Returns the index of the bucket that a roll in `[0, total)` falls into, given the thresholds
+/// from [cumulative_weights], so that each index is selected in proportion to its weight.
+/// Rolls past the total select the last index, and `None` is returned if there are no thresholds.
+pub fn select_index(cumulative: &[Decimal], roll: &Decimal) -> Option<usize> {
+	if cumulative.is_empty() {
+		return None;
+	}
+
+	Some(
+		cumulative
+			.partition_point(|threshold| threshold <= roll)
+			.min(cumulative.len() - 1),
+	)
+}
+
+/// Returns the sum of the values, adding them from the smallest magnitude to the largest,
+/// so that small values accumulate before they are added to a value large enough to drop them.
+pub fn sum_stable(values: &[Decimal]) -> Decimal {
+	let mut values = values.to_vec();
+	values.sort_by(|a, b| a.abs().total_cmp(&b.abs()));
+	values.iter().sum()
+}
+
+/// Returns the maximum of each sliding window of the given size over the values, in O(n).
+/// As with [slice::windows], there are no windows if the size is zero or larger than the slice.
+/// NaN is treated as greater than every other value.
+pub fn window_max(values: &[Decimal], window: usize) -> Vec<Decimal> {
+	if window == 0 || window > values.len() {
+		return Vec::new();
+	}
+
+	// Indices of the values which may still become the maximum of a window, whose values are decreasing.
+	let mut candidates: VecDeque<usize> = VecDeque::new();
+	let mut maxima = Vec::with_capacity(values.len() - window + 1);
+	for (index, value) in values.iter().enumerate() {
+		while let Some(&last) = candidates.back() {
+			if values[last].total_cmp(value) == Greater {
+				break;
+			}
+			candidates.pop_back();
+		}
+		candidates.push_back(index);
+
+		if candidates[0] + window <= index {
+			candidates.pop_front();
+		}
+		if index + 1 >= window {
+			maxima.push(values[candidates[0]]);
+		}
+	}
+
+	maxima
+}
+
+/// Returns the rank of the value in a slice sorted in descending order, which is how many elements are
+/// strictly greater than it, in O(log n). The result is unspecified if the slice is not sorted in descending order.
+pub fn rank_in(sorted: &[Decimal], value: &Decimal) -> usize {
+	sorted.partition_point(|element| element > value)
+}
+
+/// Returns the largest value which is strictly less than the cap, ignoring NaN,
+/// or `None` if there is no such value.
+pub fn max_below(values: &[Decimal], cap: &Decimal) -> Option<Decimal> {
+	values
+		.iter()
+		.filter(|value| !value.is_nan() && *value < cap)
+		.fold(None, |max, value| match max {
+			Some(max) if max >= *value => Some(max),
+			_ => Some(*value),
+		})
+}
+
+/// Returns whether each value is greater than or equal to the one before it.
+/// A sequence containing NaN is never monotonic.
+pub fn is_monotonic_increasing(values: &[Decimal]) -> bool {
+	!values.iter().any(Decimal::is_nan) && values.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+/// Returns whether each value is less than or equal to the one before it.
+/// A sequence containing NaN is never monotonic.
+pub fn is_monotonic_decreasing(values: &[Decimal]) -> bool {
+	!values.iter().any(Decimal::is_nan) && values.windows(2).all(|pair| pair[0] >= pair[1])
+}
+
+/// Returns the running totals of the values, such that the last element is their sum.
+pub fn cumulative_sum(values: &[Decimal]) -> Vec<Decimal> {
+	values
+		.iter()
+		.scan(Decimal::ZERO, |total, value| {
+			*total += value;
+			Some(*total)
+		})
+		.collect()
+}
+
+/// Returns the running products of the values, such that the last element is their product.
+pub fn cumulative_product(values: &[Decimal]) -> Vec<Decimal> {
+	values
+		.iter()
+		.scan(Decimal::ONE, |total, value| {
+			*total *= value;
+			Some(*total)
+		})
+		.collect()
+}
+
+/// Returns the product of the values, clamping the running product to the cap after each multiplication.
+///
+/// As the running product is clamped mid-sequence, a value less than one after the cap is reached
+/// reduces the result below the cap, unlike clamping only the final product.
+pub fn product_clamped(values: &[Decimal], cap: &Decimal) -> Decimal {
+	values
+		.iter()
+		.fold(Decimal::ONE, |total, value| (total * value).min(cap))
+}
+
+/// Combines the values like resistors in parallel, `1 / (1/x_1 + 1/x_2 + ...)`, which is smaller than every value
+/// and so suits diminishing stacked bonuses. Returns zero if any value is zero,
+/// or `Decimal::MAX_VALUE` for no values, as nothing in parallel offers no resistance.
+pub fn parallel_combine(values: &[Decimal]) -> Decimal {
+	if values.is_empty() {
+		return Decimal::MAX_VALUE;
+	} else if values.iter().any(|value| value.mantissa == 0.0) {
+		return Decimal::ZERO;
+	}
+
+	values.iter().map(Decimal::recip).sum::<Decimal>().recip()
+}
+
+/// Parses a whitespace-separated list of Decimals and returns their sum.
+/// An empty list sums to zero.
+pub fn sum_str(input: &str) -> Result<Decimal, ParseDecimalError> {
+	input
+		.split_whitespace()
+		.enumerate()
+		.map(|(index, token)| {
+			token
+				.parse::<Decimal>()
+				.map_err(|_| ParseDecimalError::InvalidToken(index))
+		})
+		.sum()
+}
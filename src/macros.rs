@@ -6,5 +6,11 @@ macro_rules! impl_from {
 				Decimal::new(num as f64)
 			}
 		}
+
+		impl ToDecimal for $from_type {
+			fn to_decimal(&self) -> Decimal {
+				Decimal::from(*self)
+			}
+		}
 	};
 }
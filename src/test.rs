@@ -19,12 +19,15 @@ fn ops() {
 	let b = super::from_mantissa_exponent_no_normalize(1.24, 53.0);
 	let c = super::from_mantissa_exponent_no_normalize(3.1, 52.0);
 
+	// The `deterministic` feature rounds away the floating-point error in the last digit.
+	#[cfg(not(feature = "deterministic"))]
+	let (a_mul_b, b_div_c) = (3.9977600000000004, 3.9999999999999996);
+	#[cfg(feature = "deterministic")]
+	let (a_mul_b, b_div_c) = (3.99776, 4.0);
+
 	assert_eq!(a + b, super::from_mantissa_exponent_no_normalize(3.348, 54.0));
 	assert_eq!(a - b, super::from_mantissa_exponent_no_normalize(3.1, 54.0));
-	assert_eq!(
-		a * b,
-		super::from_mantissa_exponent_no_normalize(3.9977600000000004, 107.0)
-	);
+	assert_eq!(a * b, super::from_mantissa_exponent_no_normalize(a_mul_b, 107.0));
 	assert_eq!(a / b, super::from_mantissa_exponent_no_normalize(2.6, 1.0));
 
 	assert_eq!(a + c, super::from_mantissa_exponent_no_normalize(3.255, 54.0));
@@ -35,10 +38,7 @@ fn ops() {
 	assert_eq!(b + c, super::from_mantissa_exponent_no_normalize(1.55, 53.0));
 	assert_eq!(b - c, super::from_mantissa_exponent_no_normalize(9.3, 52.0));
 	assert_eq!(b * c, super::from_mantissa_exponent_no_normalize(3.844, 105.0));
-	assert_eq!(
-		b / c,
-		super::from_mantissa_exponent_no_normalize(3.9999999999999996, 0.0)
-	);
+	assert_eq!(b / c, super::from_mantissa_exponent_no_normalize(b_div_c, 0.0));
 
 	assert_eq!(Decimal::new(1.0) + Decimal::new(0.0), Decimal::new(1.0));
 }
@@ -103,3 +103,2325 @@ fn neg_abs() {
 		super::from_mantissa_exponent_no_normalize(1.23, 48.0)
 	);
 }
+
+#[test]
+fn log_of_base() {
+	let a = Decimal::new(1e50);
+	assert!((a.log_of_base(&Decimal::new(2.0)) - a.log(2.0)).abs() < 1e-9);
+	assert!((a.log_of_base(&Decimal::new(10.0)) - a.log(10.0)).abs() < 1e-9);
+
+	let base = super::from_mantissa_exponent(1.0, 1e10);
+	let b = super::from_mantissa_exponent(1.0, 3e10);
+	assert!((b.log_of_base(&base) - 3.0).abs() < 1e-9);
+
+	assert!(a.log_of_base(&Decimal::ONE).is_nan());
+	assert!(a.log_of_base(&Decimal::ZERO).is_nan());
+	assert!(a.log_of_base(&Decimal::new(-2.0)).is_nan());
+}
+
+#[test]
+fn decimal_places_unnormalized() {
+	// A mantissa with more integer digits than the places requested used to underflow the fraction length.
+	let decimal = super::from_mantissa_exponent_no_normalize(123.0, 0.0);
+	assert_eq!(decimal.mantissa_with_decimal_places(0), 100.0);
+	assert_eq!(decimal.mantissa_with_decimal_places(1), 120.0);
+	assert!(decimal.to_exponential(0).ends_with("e+0"));
+	assert!(decimal.to_exponential(1).ends_with("e+0"));
+}
+
+#[test]
+fn approx() {
+	let epsilon = Decimal::new(1e-6);
+
+	assert!(Decimal::new(5e-7).is_approx_zero(&epsilon));
+	assert!(Decimal::new(-5e-7).is_approx_zero(&epsilon));
+	assert!(!Decimal::new(2e-6).is_approx_zero(&epsilon));
+
+	assert!(Decimal::new(1.0000005).is_approx_one(&epsilon));
+	assert!(Decimal::new(0.9999995).is_approx_one(&epsilon));
+	assert!(!Decimal::new(1.000002).is_approx_one(&epsilon));
+	assert!(!Decimal::new(0.999998).is_approx_one(&epsilon));
+}
+
+#[test]
+fn div_rem() {
+	assert_eq!(
+		Decimal::new(17.0).div_rem(&Decimal::new(5.0)),
+		(Decimal::new(3.0), Decimal::new(2.0))
+	);
+	assert_eq!(
+		Decimal::new(-17.0).div_rem(&Decimal::new(5.0)),
+		(Decimal::new(-4.0), Decimal::new(3.0))
+	);
+	assert_eq!(
+		Decimal::new(17.0).div_rem(&Decimal::new(-5.0)),
+		(Decimal::new(-3.0), Decimal::new(2.0))
+	);
+	assert_eq!(Decimal::new(17.0).div_euclid(&Decimal::new(5.0)), Decimal::new(3.0));
+	assert_eq!(Decimal::new(17.0).rem_euclid(&Decimal::new(5.0)), Decimal::new(2.0));
+
+	let (quotient, remainder) = Decimal::new(1e50).div_rem(&Decimal::new(3.0));
+	assert_eq!(quotient, Decimal::new(1e50) / Decimal::new(3.0));
+	assert_eq!(remainder, Decimal::ZERO);
+
+	let (quotient, remainder) = Decimal::new(7.0).div_rem(&Decimal::ZERO);
+	assert!(quotient.is_nan() && remainder.is_nan());
+	assert!(Decimal::new(7.0).rem_euclid(&Decimal::ZERO).is_nan());
+	assert!((Decimal::new(7.0) % Decimal::ZERO).is_nan());
+	let (quotient, remainder) = Decimal::NAN.div_rem(&Decimal::new(5.0));
+	assert!(quotient.is_nan() && remainder.is_nan());
+	let (quotient, remainder) = Decimal::new(7.0).div_rem(&Decimal::NAN);
+	assert!(quotient.is_nan() && remainder.is_nan());
+}
+
+#[test]
+fn to_js_string() {
+	assert_eq!(Decimal::new(0.0).to_js_string(), "0");
+	assert_eq!(Decimal::NAN.to_js_string(), "NaN");
+	assert_eq!(Decimal::new(f64::INFINITY).to_js_string(), "Infinity");
+	assert_eq!(Decimal::new(f64::NEG_INFINITY).to_js_string(), "-Infinity");
+
+	assert_eq!(Decimal::new(100.0).to_js_string(), "100");
+	assert_eq!(Decimal::new(116.0).to_js_string(), "116");
+	assert_eq!(Decimal::new(12.5).to_js_string(), "12.5");
+	assert_eq!(Decimal::new(-0.5).to_js_string(), "-0.5");
+	assert_eq!(Decimal::new(1e-6).to_js_string(), "0.000001");
+	assert_eq!(Decimal::new(1e20).to_js_string(), "100000000000000000000");
+
+	assert_eq!(Decimal::new(1e21).to_js_string(), "1e+21");
+	assert_eq!(Decimal::new(1e308).to_js_string(), "1e+308");
+	assert_eq!(Decimal::new(-2.5e100).to_js_string(), "-2.5e+100");
+	assert_eq!(Decimal::new(1.5e-10).to_js_string(), "1.5e-10");
+	assert_eq!(
+		super::from_mantissa_exponent(1.5, 1e15).to_js_string(),
+		"1.5e+1000000000000000"
+	);
+}
+
+#[test]
+fn max_min_clamp() {
+	// Ordering is by value, not by magnitude, so among negatives the one closer to zero is greater.
+	let large = Decimal::new(1e100);
+	let small = Decimal::new(1e-100);
+	let negative_small = Decimal::new(-1e-100);
+	let negative_large = Decimal::new(-1e100);
+
+	assert_eq!(large.max(&small), large);
+	assert_eq!(small.max(&large), large);
+	assert_eq!(negative_small.max(&negative_large), negative_small);
+	assert_eq!(negative_large.max(&Decimal::ZERO), Decimal::ZERO);
+
+	assert_eq!(large.min(&small), small);
+	assert_eq!(small.min(&large), small);
+	assert_eq!(negative_small.min(&negative_large), negative_large);
+	assert_eq!(negative_small.min(&Decimal::ZERO), negative_small);
+
+	// Clamp returns the value itself when it is within [min, max], otherwise the nearest bound.
+	assert_eq!(Decimal::ZERO.clamp(&negative_large, &large), Decimal::ZERO);
+	assert_eq!(large.clamp(&negative_small, &small), small);
+	assert_eq!(negative_large.clamp(&negative_small, &small), negative_small);
+
+	// Non-finite mantissas order below and above every other value.
+	let infinity = super::from_mantissa_exponent_no_normalize(f64::INFINITY, 0.0);
+	let negative_infinity = super::from_mantissa_exponent_no_normalize(f64::NEG_INFINITY, 0.0);
+	assert!(infinity > large);
+	assert!(negative_infinity < negative_large);
+	assert_eq!(large.max(&infinity), infinity);
+	assert_eq!(negative_large.min(&negative_infinity), negative_infinity);
+}
+
+#[test]
+fn magnitude_ratio() {
+	assert_eq!(Decimal::new(1e9).magnitude_ratio(&Decimal::new(1e3)), 6.0);
+	assert_eq!(Decimal::new(1e3).magnitude_ratio(&Decimal::new(1e9)), -6.0);
+	assert!((Decimal::new(2e5).magnitude_ratio(&Decimal::new(2.0)) - 5.0).abs() < 1e-12);
+
+	assert!(Decimal::ZERO.magnitude_ratio(&Decimal::ONE).is_nan());
+	assert!(Decimal::ONE.magnitude_ratio(&Decimal::NEGATIVE_ONE).is_nan());
+}
+
+#[test]
+fn normalize() {
+	let infinity = super::from_mantissa_exponent_no_normalize(f64::INFINITY, 5.0).normalize();
+	assert_eq!(infinity, Decimal::new(f64::INFINITY));
+	let negative_infinity = super::from_mantissa_exponent_no_normalize(f64::NEG_INFINITY, 5.0).normalize();
+	assert_eq!(negative_infinity, Decimal::new(f64::NEG_INFINITY));
+
+	let nan = super::from_mantissa_exponent_no_normalize(f64::NAN, 5.0).normalize();
+	assert_eq!(nan.to_string(), "NaN");
+
+	let large = super::from_mantissa_exponent_no_normalize(1.5e300, 5.0).normalize();
+	assert_eq!(large.exponent, 305.0);
+	assert!((large.mantissa - 1.5).abs() < 1e-12);
+
+	assert_eq!("infe5".parse::<Decimal>().unwrap(), Decimal::new(f64::INFINITY));
+}
+
+#[test]
+fn new_precise() {
+	let value = 1.2345678901234567;
+	assert_eq!(Decimal::new_precise(value).to_number(), value);
+	assert_ne!(Decimal::new(value).to_number(), value);
+
+	assert_eq!(Decimal::new_precise(0.0), Decimal::ZERO);
+	assert_eq!(Decimal::new_precise(f64::INFINITY), Decimal::new(f64::INFINITY));
+	assert_eq!(Decimal::new_precise(100.0), Decimal::new(100.0));
+}
+
+#[test]
+fn is_power_of_ten() {
+	assert!(Decimal::new(1e100).is_power_of_ten());
+	assert!(Decimal::new(1e-5).is_power_of_ten());
+	assert!(Decimal::ONE.is_power_of_ten());
+	assert!(!Decimal::new(2e100).is_power_of_ten());
+	assert!(!Decimal::new(1.0000001e100).is_power_of_ten());
+	assert!(!Decimal::ZERO.is_power_of_ten());
+	assert!(!Decimal::new(-1e100).is_power_of_ten());
+	assert!(!Decimal::NAN.is_power_of_ten());
+}
+
+#[test]
+fn conversion() {
+	use super::ToDecimal;
+
+	fn convert<T: Into<Decimal>>(amount: T) -> Decimal {
+		amount.into()
+	}
+
+	let decimal = Decimal::new(1e100);
+	let reference = &decimal;
+	assert_eq!(convert(5_i32), Decimal::new(5.0));
+	assert_eq!(convert(2.5_f64), Decimal::new(2.5));
+	assert_eq!(convert(reference), decimal);
+	assert_eq!(convert(f64::NAN).to_string(), "NaN");
+	assert_eq!(convert(f32::INFINITY), Decimal::new(f64::INFINITY));
+
+	assert_eq!(5_u8.to_decimal(), Decimal::new(5.0));
+	assert_eq!((-3_i64).to_decimal(), Decimal::new(-3.0));
+	assert_eq!(decimal.to_decimal(), decimal);
+}
+
+#[test]
+fn round_to_multiple() {
+	let five = Decimal::new(5.0);
+	assert_eq!(Decimal::new(47.0).round_to_multiple(&five), Decimal::new(45.0));
+	assert_eq!(Decimal::new(48.0).round_to_multiple(&five), Decimal::new(50.0));
+	assert_eq!(Decimal::new(-48.0).round_to_multiple(&five), Decimal::new(-50.0));
+	assert_eq!(
+		Decimal::new(4.7e50).round_to_multiple(&Decimal::new(1e50)),
+		Decimal::new(5e50)
+	);
+	assert_eq!(Decimal::new(47.0).round_to_multiple(&Decimal::ZERO), Decimal::new(47.0));
+}
+
+#[test]
+fn checked_overflow() {
+	let half = super::from_mantissa_exponent(1.0, (super::EXP_LIMIT / 2.0).floor());
+	// Slightly over half of the limit, so that its square is past the limit while its exponent still fits in an f64.
+	let over_half = super::from_mantissa_exponent(1.0, (super::EXP_LIMIT * 0.501).floor());
+	let infinity = Decimal::new(f64::INFINITY);
+
+	assert_eq!(
+		Decimal::new(2.0).mul_checked_overflow(&Decimal::new(3.0)),
+		(Decimal::new(6.0), false)
+	);
+	assert!(!half.mul_checked_overflow(&Decimal::new(10.0)).1);
+	assert_eq!(over_half.mul_checked_overflow(&over_half), (infinity, true));
+	assert_eq!(over_half.mul_checked_overflow(&-over_half), (-infinity, true));
+
+	assert_eq!(
+		Decimal::new(2.0).add_checked_overflow(&Decimal::new(3.0)),
+		(Decimal::new(5.0), false)
+	);
+	// A result at the limit is the infinity sentinel itself rather than an overflow past it.
+	assert_eq!(half.add_checked_overflow(&infinity), (infinity, false));
+
+	assert_eq!(
+		Decimal::new(2.0).pow_checked_overflow(&Decimal::new(10.0)),
+		(Decimal::new(1024.0), false)
+	);
+	assert_eq!(over_half.pow_checked_overflow(&Decimal::new(2.0)), (infinity, true));
+}
+
+#[test]
+fn cmp_exponent() {
+	use std::cmp::Ordering::*;
+
+	assert_eq!(Decimal::new(1e10).cmp_exponent(&Decimal::new(9e9)), Greater);
+	assert_eq!(Decimal::new(1e9).cmp_exponent(&Decimal::new(9e9)), Equal);
+	assert_eq!(Decimal::new(9e9).cmp_exponent(&Decimal::new(1e9)), Equal);
+	assert_eq!(Decimal::new(1e-5).cmp_exponent(&Decimal::new(1e5)), Less);
+
+	assert_eq!(Decimal::new(-1e10).cmp_exponent(&Decimal::new(-1e9)), Less);
+	assert_eq!(Decimal::new(-1e10).cmp_exponent(&Decimal::new(1e-9)), Less);
+	assert_eq!(Decimal::ZERO.cmp_exponent(&Decimal::new(-1e-9)), Greater);
+	assert_eq!(Decimal::ZERO.cmp_exponent(&Decimal::new(1e-9)), Less);
+	assert_eq!(Decimal::ZERO.cmp_exponent(&Decimal::ZERO), Equal);
+}
+
+#[test]
+fn log_lerp() {
+	let a = Decimal::new(1e2);
+	let b = Decimal::new(1e6);
+
+	assert_eq!(Decimal::log_lerp(&a, &b, 0.5), Decimal::new(1e4));
+	assert_eq!(Decimal::log_lerp(&a, &b, 0.0), a);
+	assert_eq!(Decimal::log_lerp(&a, &b, 1.0), b);
+	assert_eq!(Decimal::log_lerp(&b, &a, 0.25), Decimal::new(1e5));
+	assert_eq!(
+		Decimal::log_lerp(&Decimal::new(1e3), &Decimal::new(1e9), 0.5),
+		Decimal::new(1e6)
+	);
+
+	assert_eq!(Decimal::log_lerp(&Decimal::ZERO, &b, 0.5).to_string(), "NaN");
+	assert_eq!(Decimal::log_lerp(&a, &Decimal::new(-1e6), 0.5).to_string(), "NaN");
+}
+
+#[test]
+fn from_digits() {
+	use super::ParseDecimalError;
+
+	let decimal = Decimal::from_digits("123456789012345678901234567890").unwrap();
+	assert_eq!(
+		decimal,
+		super::from_mantissa_exponent_no_normalize(1.2345678901234568, 29.0)
+	);
+
+	let negative = Decimal::from_digits("-000987654321098765432109876543210").unwrap();
+	assert_eq!(
+		negative,
+		super::from_mantissa_exponent_no_normalize(-9.876543210987654, 29.0)
+	);
+
+	assert_eq!(Decimal::from_digits("+42"), Ok(Decimal::new(42.0)));
+	assert_eq!(Decimal::from_digits("0000"), Ok(Decimal::ZERO));
+	assert_eq!(Decimal::from_digits(""), Err(ParseDecimalError::Empty));
+	assert_eq!(Decimal::from_digits("-"), Err(ParseDecimalError::Empty));
+	assert_eq!(Decimal::from_digits("12.5"), Err(ParseDecimalError::InvalidDigit));
+	assert_eq!(Decimal::from_digits("1e5"), Err(ParseDecimalError::InvalidDigit));
+}
+
+#[test]
+fn smooth_clamp() {
+	let ceiling = Decimal::new(1e10);
+	let relative_error = |a: Decimal, b: Decimal| ((a - b) / b).abs().to_number();
+
+	assert!(relative_error(Decimal::new(1.0).smooth_clamp(&ceiling, 1.0), Decimal::new(1.0)) < 1e-6);
+	assert!(relative_error(Decimal::new(1e3).smooth_clamp(&ceiling, 0.5), Decimal::new(1e3)) < 1e-6);
+	assert!(relative_error(Decimal::new(1e20).smooth_clamp(&ceiling, 1.0), ceiling) < 1e-6);
+	assert!(relative_error(Decimal::new(-1e20).smooth_clamp(&ceiling, 1.0), -ceiling) < 1e-6);
+
+	let knee = ceiling.smooth_clamp(&ceiling, 1.0);
+	assert!(knee < ceiling && knee > Decimal::new(7e9));
+	assert!(knee < ceiling.smooth_clamp(&ceiling, 0.5));
+
+	assert_eq!(Decimal::new(1e20).smooth_clamp(&ceiling, 0.0), ceiling);
+	assert_eq!(Decimal::ZERO.smooth_clamp(&ceiling, 1.0), Decimal::ZERO);
+}
+
+#[test]
+fn to_mantissa_exp_i32() {
+	assert_eq!(Decimal::new(2.5e100).to_mantissa_exp_i32(), Some((2.5, 100)));
+	assert_eq!(Decimal::new(-0.5).to_mantissa_exp_i32(), Some((-5.0, -1)));
+	assert_eq!(super::from_mantissa_exponent(1.5, 1e10).to_mantissa_exp_i32(), None);
+	assert_eq!(super::from_mantissa_exponent(1.5, -1e10).to_mantissa_exp_i32(), None);
+	assert_eq!(Decimal::new(f64::NAN).to_mantissa_exp_i32(), None);
+}
+
+#[test]
+fn sum_geometric_series_saturating() {
+	use super::{sum_geometric_series, sum_geometric_series_saturating};
+
+	let start = Decimal::new(10.0);
+	let ratio = Decimal::new(1.1);
+	let owned = Decimal::new(5.0);
+
+	let small = Decimal::new(20.0);
+	assert_eq!(
+		sum_geometric_series_saturating(&small, &start, &ratio, &owned),
+		sum_geometric_series(&small, &start, &ratio, &owned)
+	);
+
+	let large = Decimal::new(1e12);
+	let sum = sum_geometric_series_saturating(&large, &start, &ratio, &owned);
+	let expected = 1.0 + (1e12 + 5.0) * 1.1_f64.log10() - 0.1_f64.log10();
+	assert!((sum.log10() - expected).abs() < 1e-3);
+
+	let huge = super::from_mantissa_exponent(1.0, 400.0);
+	assert_eq!(
+		sum_geometric_series_saturating(&huge, &start, &ratio, &owned),
+		Decimal::MAX_VALUE
+	);
+	assert_eq!(
+		sum_geometric_series_saturating(&small, &start, &Decimal::ONE, &owned),
+		Decimal::new(200.0)
+	);
+}
+
+#[test]
+fn ln_full() {
+	let decimal = Decimal::new(1e100);
+	assert!((decimal.ln_full().to_number() - decimal.ln()).abs() < 1e-9);
+	assert_eq!(Decimal::ONE.ln_full(), Decimal::ZERO);
+}
+
+#[test]
+#[cfg(feature = "full-range")]
+fn ln_full_range() {
+	let decimal = super::from_mantissa_exponent(9.0, 1.7e308);
+	assert!(decimal.ln().is_infinite());
+
+	let ln = decimal.ln_full();
+	assert!(ln.mantissa.is_finite());
+	assert_eq!(ln.exponent, 308.0);
+	assert!((ln.mantissa - 1.7 * std::f64::consts::LN_10).abs() < 1e-9);
+}
+
+#[test]
+fn affordable_costs() {
+	use super::affordable_costs;
+
+	let start = Decimal::new(10.0);
+	let ratio = Decimal::new(2.0);
+
+	let costs = affordable_costs(&Decimal::new(100.0), &start, &ratio, &Decimal::ZERO, 10);
+	assert_eq!(costs, vec![Decimal::new(10.0), Decimal::new(30.0), Decimal::new(70.0)]);
+
+	let costs = affordable_costs(&Decimal::new(100.0), &start, &ratio, &Decimal::new(2.0), 10);
+	assert_eq!(costs, vec![Decimal::new(40.0)]);
+
+	let costs = affordable_costs(&Decimal::new(1e100), &start, &ratio, &Decimal::ZERO, 2);
+	assert_eq!(costs, vec![Decimal::new(10.0), Decimal::new(30.0)]);
+
+	assert!(affordable_costs(&Decimal::new(5.0), &start, &ratio, &Decimal::ZERO, 10).is_empty());
+}
+
+#[test]
+fn constants() {
+	assert_eq!(Decimal::TEN, Decimal::new(10.0));
+	assert_eq!(Decimal::HUNDRED, Decimal::new(100.0));
+	assert_eq!(Decimal::THOUSAND, Decimal::new(1e3));
+	assert_eq!(Decimal::MILLION, Decimal::new(1e6));
+	assert_eq!(Decimal::BILLION, Decimal::new(1e9));
+	assert_eq!(Decimal::TRILLION, Decimal::new(1e12));
+}
+
+#[test]
+fn parse_display_round_trip() {
+	let tolerance = Decimal::new(1e-12);
+	let mantissas = [
+		1.0,
+		1.5,
+		std::f64::consts::E,
+		3.3333333333333335,
+		5.0,
+		9.999999999999998,
+	];
+
+	let mut exponents: Vec<f64> = (-400..=400).map(f64::from).collect();
+	for power in 3..16 {
+		for scale in [1.0, 2.5, 7.3] {
+			let exponent = (scale * 10.0_f64.powi(power)).round();
+			if exponent < super::EXP_LIMIT {
+				exponents.push(exponent);
+				exponents.push(-exponent);
+			}
+		}
+	}
+
+	for exponent in exponents {
+		for mantissa in mantissas {
+			for sign in [1.0, -1.0] {
+				let decimal = super::from_mantissa_exponent(sign * mantissa, exponent);
+				let parsed: Decimal = decimal.to_string().parse().unwrap();
+				assert!(
+					parsed.eq_tolerance(&decimal, &tolerance),
+					"{:?} was parsed as {:?}",
+					decimal,
+					parsed
+				);
+			}
+		}
+	}
+
+	for string in ["0", "NaN", "Infinity", "-Infinity"] {
+		assert_eq!(string.parse::<Decimal>().unwrap().to_string(), string);
+	}
+	assert_eq!(
+		"1E400".parse::<Decimal>().unwrap(),
+		super::from_mantissa_exponent(1.0, 400.0)
+	);
+
+	assert!(!Decimal::new(1.0).eq_tolerance(&Decimal::new(1.1), &tolerance));
+	assert!(Decimal::new(1e100).eq_tolerance(&Decimal::new(1.0000000000001e100), &tolerance));
+}
+
+#[test]
+fn log_progress() {
+	let from = Decimal::ONE;
+	let to = Decimal::new(1e10);
+
+	assert_eq!(Decimal::new(1e5).log_progress(&from, &to), 0.5);
+	assert_eq!(Decimal::new(1e2).log_progress(&from, &to), 0.2);
+	assert_eq!(Decimal::new(0.5).log_progress(&from, &to), 0.0);
+	assert_eq!(Decimal::ZERO.log_progress(&from, &to), 0.0);
+	assert_eq!(Decimal::new(1e20).log_progress(&from, &to), 1.0);
+	assert_eq!(to.log_progress(&from, &to), 1.0);
+
+	assert!(Decimal::new(1e5).log_progress(&Decimal::ZERO, &to).is_nan());
+	assert!(Decimal::new(1e5).log_progress(&Decimal::new(-1.0), &to).is_nan());
+	assert!(Decimal::new(1e5).log_progress(&to, &from).is_nan());
+	assert!(Decimal::new(1e5).log_progress(&to, &to).is_nan());
+}
+
+#[test]
+fn sign() {
+	assert_eq!(Decimal::new(-1.23e45).sign(), -1.0);
+	assert_eq!(Decimal::new(1.23e45).sign(), 1.0);
+	assert_eq!(Decimal::ZERO.sign(), 0.0);
+
+	assert_eq!(Decimal::new(-1.23e45).split_sign(), (-1.0, Decimal::new(1.23e45)));
+	assert_eq!(Decimal::new(1.23e-45).split_sign(), (1.0, Decimal::new(1.23e-45)));
+	assert_eq!(Decimal::ZERO.split_sign(), (0.0, Decimal::ZERO));
+}
+
+#[test]
+fn in_range() {
+	let low = Decimal::new(1e3);
+	let high = Decimal::new(1e6);
+
+	assert!(Decimal::new(5e4).in_range(&low, &high, true));
+	assert!(Decimal::new(5e4).in_range(&low, &high, false));
+	assert!(low.in_range(&low, &high, true));
+	assert!(high.in_range(&low, &high, true));
+	assert!(!low.in_range(&low, &high, false));
+	assert!(!high.in_range(&low, &high, false));
+	assert!(!Decimal::new(1e7).in_range(&low, &high, true));
+	assert!(!Decimal::new(-5e4).in_range(&low, &high, true));
+	assert!(!Decimal::NAN.in_range(&low, &high, true));
+}
+
+#[test]
+fn factorial() {
+	let relative_error = |a: Decimal, b: Decimal| ((a - b) / b).abs().to_number();
+
+	assert!(relative_error(Decimal::new(5.0).factorial(), Decimal::new(120.0)) < 1e-6);
+	assert!(relative_error(Decimal::new(20.0).factorial(), Decimal::new(2432902008176640000.0)) < 1e-6);
+
+	let factorial = Decimal::new(1000.0).factorial();
+	assert_eq!(factorial.exponent, 2567.0);
+	assert!((factorial.mantissa - 4.0238726007709).abs() < 1e-6);
+}
+
+#[test]
+fn quantize() {
+	for value in [1.2345678e50, -9.87654321e-20, 4.56789, 9.9999999e300] {
+		let decimal = Decimal::new(value);
+		for bits in [4, 8, 16, 32] {
+			let quantized = decimal.quantize(bits);
+			let relative_error = ((quantized - decimal) / decimal).abs().to_number();
+			assert!(relative_error <= 2.0_f64.powi(-(bits as i32 + 1)));
+		}
+	}
+
+	assert_eq!(Decimal::new(1.5e10).quantize(1), Decimal::new(1.5e10));
+	assert_eq!(Decimal::new(1.3e10).quantize(1), Decimal::new(1.5e10));
+	assert_eq!(Decimal::new(9.9e10).quantize(2), Decimal::new(1e11));
+	assert_eq!(Decimal::ZERO.quantize(8), Decimal::ZERO);
+}
+
+#[test]
+fn cumulative() {
+	use super::{cumulative_product, cumulative_sum};
+
+	let values = [
+		Decimal::new(2.0),
+		Decimal::new(3.0),
+		Decimal::new(1e50),
+		Decimal::new(0.5),
+	];
+
+	let sums = cumulative_sum(&values);
+	assert_eq!(sums.len(), values.len());
+	assert_eq!(sums[1], Decimal::new(5.0));
+	assert_eq!(sums[3], values.iter().fold(Decimal::ZERO, |total, value| total + value));
+
+	let products = cumulative_product(&values);
+	assert_eq!(products[1], Decimal::new(6.0));
+	assert_eq!(products[3], Decimal::new(3e50));
+
+	assert!(cumulative_sum(&[]).is_empty());
+	assert!(cumulative_product(&[]).is_empty());
+}
+
+#[test]
+fn to_duration_string() {
+	assert_eq!(Decimal::new(3661.0).to_duration_string(), "1h 1m 1s");
+	assert_eq!(Decimal::new(3600.0).to_duration_string(), "1h");
+	assert_eq!(Decimal::new(90061.5).to_duration_string(), "1d 1h 1m 1s");
+	assert_eq!(Decimal::new(31_536_061.0).to_duration_string(), "1y 1m 1s");
+	assert_eq!(Decimal::new(0.5).to_duration_string(), "0.5s");
+	assert_eq!(Decimal::ZERO.to_duration_string(), "0s");
+	assert_eq!(Decimal::new(-61.0).to_duration_string(), "-1m 1s");
+	assert_eq!(Decimal::new(1e20).to_duration_string(), "3.17e+12y");
+}
+
+#[test]
+fn try_from_mantissa_exponent() {
+	use super::{from_mantissa_exponent, try_from_mantissa_exponent, EXP_LIMIT};
+
+	assert_eq!(
+		try_from_mantissa_exponent(12.5, 100.0),
+		Ok(from_mantissa_exponent(1.25, 101.0))
+	);
+	assert_eq!(try_from_mantissa_exponent(0.0, 100.0), Ok(Decimal::ZERO));
+	assert!(try_from_mantissa_exponent(1.0, EXP_LIMIT).is_err());
+	assert!(try_from_mantissa_exponent(50.0, EXP_LIMIT - 1.0).is_err());
+	assert!(try_from_mantissa_exponent(1.0, -EXP_LIMIT).is_err());
+	assert!(try_from_mantissa_exponent(f64::NAN, 0.0).is_err());
+	assert!(try_from_mantissa_exponent(1.0, f64::INFINITY).is_err());
+}
+
+#[test]
+fn growth_rate() {
+	let start = Decimal::new(1e10);
+	let rate = Decimal::new(1.05);
+	let end = start * rate.pow(&Decimal::new(100.0));
+
+	let growth = Decimal::growth_rate(&start, &end, 100.0);
+	assert!((growth.to_number() - 1.05).abs() < 1e-9);
+	assert!((Decimal::new(1e12).root(3.0).to_number() - 1e4).abs() < 1e-6);
+
+	assert_eq!(Decimal::growth_rate(&Decimal::ZERO, &end, 100.0).to_string(), "NaN");
+	assert_eq!(Decimal::growth_rate(&start, &-end, 100.0).to_string(), "NaN");
+}
+
+#[test]
+fn nan_propagation() {
+	let operands = [
+		Decimal::ZERO,
+		Decimal::ONE,
+		Decimal::new(-1e50),
+		Decimal::new(1e-50),
+		Decimal::new(f64::INFINITY),
+	];
+
+	for nan in [Decimal::NAN, Decimal::new(f64::NAN)] {
+		for operand in operands {
+			assert!((nan + operand).is_nan());
+			assert!((operand + nan).is_nan());
+			assert!((nan - operand).is_nan());
+			assert!((operand - nan).is_nan());
+			assert!((nan * operand).is_nan());
+			assert!((operand * nan).is_nan());
+			assert!((nan / operand).is_nan());
+			assert!((operand / nan).is_nan());
+		}
+	}
+}
+
+#[test]
+fn to_layer_mantissa() {
+	let (layer, residual) = Decimal::new(1e100).to_layer_mantissa();
+	assert_eq!(layer, 0);
+	assert!((residual / 1e100 - 1.0).abs() < 1e-12);
+	assert_eq!(Decimal::new(-2.5).to_layer_mantissa(), (0, 2.5));
+	assert_eq!(Decimal::ZERO.to_layer_mantissa(), (0, 0.0));
+	assert_eq!(
+		super::from_mantissa_exponent(1.0, 1000.0).to_layer_mantissa(),
+		(1, 1000.0)
+	);
+	assert_eq!(
+		super::from_mantissa_exponent(1.0, -1000.0).to_layer_mantissa(),
+		(1, -1000.0)
+	);
+	assert_eq!(super::from_mantissa_exponent(1.0, 1e15).to_layer_mantissa(), (1, 1e15));
+}
+
+#[test]
+#[cfg(feature = "full-range")]
+fn to_layer_mantissa_full_range() {
+	assert_eq!(
+		super::from_mantissa_exponent(1.0, 1.7e308).to_layer_mantissa(),
+		(1, 1.7e308)
+	);
+}
+
+#[test]
+fn blend() {
+	let a = Decimal::new(10.0);
+	let b = Decimal::new(40.0);
+
+	assert_eq!(a.blend(&b, &Decimal::new(2.0), &Decimal::new(1.0)), Decimal::new(20.0));
+	assert_eq!(
+		a.blend(&b, &Decimal::new(1e300), &Decimal::new(1e300)),
+		Decimal::new(25.0)
+	);
+	assert_eq!(a.blend(&b, &Decimal::ZERO, &Decimal::new(5.0)), b);
+	assert!(a.blend(&b, &Decimal::ZERO, &Decimal::ZERO).is_nan());
+	assert!(a.blend(&b, &Decimal::ONE, &Decimal::NEGATIVE_ONE).is_nan());
+}
+
+#[test]
+fn power_of_ten_bounds() {
+	assert_eq!(Decimal::new(3.4e5).ceil_power_of_ten(), Decimal::new(1e6));
+	assert_eq!(Decimal::new(3.4e5).floor_power_of_ten(), Decimal::new(1e5));
+	assert_eq!(Decimal::new(1e5).ceil_power_of_ten(), Decimal::new(1e5));
+	assert_eq!(Decimal::new(1e5).floor_power_of_ten(), Decimal::new(1e5));
+	assert_eq!(Decimal::new(0.05).ceil_power_of_ten(), Decimal::new(0.1));
+	assert_eq!(Decimal::new(0.05).floor_power_of_ten(), Decimal::new(0.01));
+
+	assert!(Decimal::ZERO.ceil_power_of_ten().is_nan());
+	assert!(Decimal::new(-3.4e5).floor_power_of_ten().is_nan());
+}
+
+#[test]
+fn new_powers_of_ten() {
+	for power in -10..=20 {
+		let value: f64 = format!("1e{}", power).parse().unwrap();
+		for decimal in [Decimal::new(value), Decimal::new(-value)] {
+			assert_eq!(
+				decimal.mantissa.abs(),
+				1.0,
+				"1e{} has mantissa {}",
+				power,
+				decimal.mantissa
+			);
+			assert_eq!(decimal.exponent, power as f64);
+		}
+	}
+}
+
+#[test]
+fn symmetric_percent_diff() {
+	let a = Decimal::new(1.02e100);
+	let b = Decimal::new(1e100);
+
+	assert!((a.symmetric_percent_diff(&b) - 0.02 / 1.01).abs() < 1e-12);
+	assert_eq!(a.symmetric_percent_diff(&b), b.symmetric_percent_diff(&a));
+	assert_eq!(a.symmetric_percent_diff(&a), 0.0);
+	assert!(a.symmetric_percent_diff(&-a).is_nan());
+}
+
+#[test]
+fn standard_notation() {
+	assert_eq!(Decimal::new(0.3).to_string(), "0.3");
+	assert_eq!(Decimal::new(-1.5e-5).to_string(), "-0.000015");
+	assert_eq!(Decimal::new(1e-6).to_string(), "0.000001");
+	assert_eq!(Decimal::new(1e-7).to_string(), "1.0000000000000000e-7");
+
+	assert_eq!(Decimal::new(1e-7).to_string_with_min_exponent(-10), "0.0000001");
+	assert_eq!(
+		Decimal::new(1.25e-10).to_string_with_min_exponent(-10),
+		"0.000000000125"
+	);
+	assert_eq!(
+		Decimal::new(1e-11).to_string_with_min_exponent(-10),
+		"1.0000000000000000e-11"
+	);
+	assert_eq!(
+		Decimal::new(1e-5).to_string_with_min_exponent(-3),
+		"1.0000000000000000e-5"
+	);
+	assert_eq!(Decimal::new(1234.0).to_string_with_min_exponent(-10), "1234");
+	assert_eq!(Decimal::ZERO.to_string_with_min_exponent(-10), "0");
+}
+
+#[test]
+fn to_u128_key() {
+	assert_eq!(Decimal::new(1.5e100).to_u128_key(), Decimal::new(1.5e100).to_u128_key());
+	assert_eq!(Decimal::new(1e3).to_u128_key(), Decimal::THOUSAND.to_u128_key());
+	assert_eq!(Decimal::NAN.to_u128_key(), Decimal::new(f64::NAN).to_u128_key());
+	assert_eq!(Decimal::ZERO.to_u128_key(), Decimal::new(-0.0).to_u128_key());
+	assert_eq!(
+		Decimal::ZERO.to_u128_key(),
+		super::from_mantissa_exponent_no_normalize(0.0, 5.0).to_u128_key()
+	);
+
+	assert_ne!(Decimal::new(1.5e100).to_u128_key(), Decimal::new(1.5e101).to_u128_key());
+	assert_ne!(
+		Decimal::new(1.5e100).to_u128_key(),
+		Decimal::new(-1.5e100).to_u128_key()
+	);
+	assert_ne!(Decimal::new(1.5e100).to_u128_key(), Decimal::new(1.6e100).to_u128_key());
+	assert_ne!(Decimal::ZERO.to_u128_key(), Decimal::NAN.to_u128_key());
+}
+
+#[test]
+fn price_after() {
+	use super::price_after;
+
+	let start = Decimal::new(10.0);
+	let ratio = Decimal::new(1.15);
+	let owned = Decimal::new(20.0);
+
+	for buying in [0.0, 1.0, 25.0, 1e4] {
+		let buying = Decimal::new(buying);
+		assert_eq!(
+			price_after(&start, &ratio, &owned, &buying),
+			start * ratio.pow(&(owned + buying))
+		);
+	}
+	assert_eq!(
+		price_after(&start, &Decimal::new(2.0), &Decimal::new(2.0), &Decimal::new(3.0)),
+		Decimal::new(320.0)
+	);
+}
+
+#[test]
+fn min_ord() {
+	use super::MinOrd;
+	use std::collections::BinaryHeap;
+
+	let mut heap = BinaryHeap::new();
+	for value in [1e10, -5.0, 3.0, 1e-10, 0.0, 1e300] {
+		heap.push(MinOrd(Decimal::new(value)));
+	}
+
+	let popped: Vec<Decimal> = std::iter::from_fn(|| heap.pop().map(|MinOrd(decimal)| decimal)).collect();
+	let expected: Vec<Decimal> = [-5.0, 0.0, 1e-10, 3.0, 1e10, 1e300]
+		.iter()
+		.map(|&value| Decimal::new(value))
+		.collect();
+	assert_eq!(popped, expected);
+
+	assert!(Decimal::NAN.total_cmp(&Decimal::new(f64::INFINITY)).is_gt());
+	assert!(Decimal::NAN.total_cmp(&Decimal::new(f64::NAN)).is_eq());
+}
+
+#[test]
+fn doublings_until() {
+	assert!((Decimal::ONE.doublings_until(&Decimal::new(1024.0)) - 10.0).abs() < 1e-12);
+	assert!((Decimal::new(1024.0).doublings_until(&Decimal::ONE) + 10.0).abs() < 1e-12);
+	assert!((Decimal::new(3e100).doublings_until(&Decimal::new(6e100)) - 1.0).abs() < 1e-9);
+	assert!(Decimal::ZERO.doublings_until(&Decimal::ONE).is_nan());
+	assert!(Decimal::ONE.doublings_until(&Decimal::NEGATIVE_ONE).is_nan());
+}
+
+#[test]
+fn base62() {
+	use super::ParseDecimalError;
+
+	for decimal in [
+		Decimal::new(1.2345e100),
+		Decimal::new(-9.87e-200),
+		Decimal::ONE,
+		Decimal::ZERO,
+		Decimal::new(f64::INFINITY),
+		super::from_mantissa_exponent(1.5, 1e15),
+	] {
+		let encoded = decimal.to_base62();
+		assert!(encoded.len() <= 22);
+		assert!(encoded.bytes().all(|byte| byte.is_ascii_alphanumeric()));
+		assert_eq!(Decimal::from_base62(&encoded), Ok(decimal));
+	}
+
+	assert!(Decimal::from_base62(&Decimal::NAN.to_base62()).unwrap().is_nan());
+
+	assert_eq!(Decimal::from_base62(""), Err(ParseDecimalError::Empty));
+	assert_eq!(Decimal::from_base62("abc-def"), Err(ParseDecimalError::InvalidDigit));
+	assert_eq!(
+		Decimal::from_base62("zzzzzzzzzzzzzzzzzzzzzz"),
+		Err(ParseDecimalError::Overflow)
+	);
+}
+
+#[test]
+fn percent_of_total() {
+	let total = Decimal::new(4e100);
+
+	assert!((Decimal::new(1e100).percent_of_total(&total) - 25.0).abs() < 1e-12);
+	assert!(Decimal::new(1e50).percent_of_total(&total) < 1e-40);
+	assert_eq!(Decimal::new(8e100).percent_of_total(&total), 100.0);
+	assert_eq!(Decimal::new(-1e100).percent_of_total(&total), 0.0);
+	assert_eq!(Decimal::new(1e100).percent_of_total(&Decimal::ZERO), 0.0);
+}
+
+#[test]
+#[cfg(feature = "nan-on-overflow")]
+fn nan_on_overflow() {
+	let half = super::from_mantissa_exponent(1.0, (super::EXP_LIMIT / 2.0).floor());
+	let large = super::from_mantissa_exponent(1.0, super::EXP_LIMIT - 1.0);
+
+	assert!((half * half * half).is_nan());
+	assert!((large * half).is_nan());
+	assert!(half.pow(&Decimal::new(3.0)).is_nan());
+	assert!(!(large * Decimal::new(10.0)).is_nan());
+	assert!(!(large + large).is_nan());
+	assert_eq!(Decimal::new(2.0) * Decimal::new(3.0), Decimal::new(6.0));
+
+	// Slightly over half of the limit, so that its square is past the limit while its exponent still fits in an f64.
+	let over_half = super::from_mantissa_exponent(1.0, (super::EXP_LIMIT * 0.501).floor());
+	assert!(over_half.sqr().is_nan());
+	assert!(half.cube().is_nan());
+	assert!(Decimal::pow10(super::EXP_LIMIT * 1.001).is_nan());
+	assert!(!half.sqr().is_nan());
+	assert_eq!(Decimal::new(3.0).sqr(), Decimal::new(9.0));
+	assert_eq!(Decimal::new(2.0).cube(), Decimal::new(8.0));
+}
+
+#[test]
+fn rank_purchases() {
+	let options = [
+		(Decimal::new(1000.0), Decimal::new(10.0), Decimal::new(100.0)),
+		(Decimal::new(50.0), Decimal::new(10.0), Decimal::new(5.0)),
+		(Decimal::new(10.0), Decimal::new(10.0), Decimal::ZERO),
+		(Decimal::new(100.0), Decimal::new(10.0), Decimal::new(1.0)),
+		(Decimal::new(20.0), Decimal::new(10.0), Decimal::new(-2.0)),
+	];
+
+	let mut expected = vec![0, 1, 3];
+	expected.sort_by(|&a, &b| {
+		let (cost_a, rp_s_a, delta_a) = &options[a];
+		let (cost_b, rp_s_b, delta_b) = &options[b];
+		super::efficiency_of_purchase(cost_a, rp_s_a, delta_a)
+			.partial_cmp(&super::efficiency_of_purchase(cost_b, rp_s_b, delta_b))
+			.unwrap()
+	});
+
+	assert_eq!(super::rank_purchases(&options), expected);
+	assert_eq!(super::rank_purchases(&options), vec![3, 1, 0]);
+	assert!(super::rank_purchases(&[]).is_empty());
+}
+
+#[test]
+fn break_even_time() {
+	assert_eq!(
+		super::break_even_time(&Decimal::new(100.0), &Decimal::new(4.0)),
+		Decimal::new(25.0)
+	);
+	assert_eq!(
+		super::break_even_time(&Decimal::new(1e10), &Decimal::new(1e5)),
+		Decimal::new(1e5)
+	);
+	assert_eq!(
+		super::break_even_time(&Decimal::new(100.0), &Decimal::ZERO),
+		Decimal::MAX_VALUE
+	);
+}
+
+#[test]
+fn sum_str() {
+	use super::ParseDecimalError;
+
+	assert_eq!(super::sum_str("1e3 2e3 3e3"), Ok(Decimal::new(6000.0)));
+	assert_eq!(super::sum_str("  1\t2\n3  "), Ok(Decimal::new(6.0)));
+	assert_eq!(super::sum_str(""), Ok(Decimal::ZERO));
+	assert_eq!(
+		super::sum_str("1e3 abc 3e3 xyz"),
+		Err(ParseDecimalError::InvalidToken(1))
+	);
+
+	let values = [Decimal::new(1.0), Decimal::new(2.0), Decimal::new(3.0)];
+	assert_eq!(values.iter().sum::<Decimal>(), Decimal::new(6.0));
+	assert_eq!(values.iter().copied().sum::<Decimal>(), Decimal::new(6.0));
+}
+
+#[test]
+fn to_number_rounding() {
+	assert_eq!(Decimal::new(116.0).to_number(), 116.0);
+	assert_eq!(super::from_mantissa_exponent(1.16, 2.0).to_number(), 116.0);
+	assert_eq!(super::from_mantissa_exponent(1.2345678, 7.0).to_number(), 12345678.0);
+	assert_eq!(Decimal::new(1e15 + 1.0).to_number(), 1e15 + 1.0);
+	assert!((Decimal::new(116.4).to_number() - 116.4).abs() < 1e-12);
+	assert_ne!(Decimal::new(116.4).to_number(), 116.0);
+	assert_ne!(Decimal::new(27182818284.59).to_number(), 27182818285.0);
+	assert_eq!(Decimal::new(0.5).to_number(), 0.5);
+	assert_eq!(Decimal::new(-116.0).to_number(), -116.0);
+}
+
+#[test]
+fn principal_root() {
+	let tolerance = Decimal::new(1e-12);
+
+	assert_eq!(Decimal::new(1e8).principal_root(4), Decimal::new(100.0));
+	assert_eq!(Decimal::new(-27.0).principal_root(3), Decimal::new(-3.0));
+	assert_eq!(Decimal::new(5.0).principal_root(1), Decimal::new(5.0));
+	assert_eq!(Decimal::ZERO.principal_root(3), Decimal::ZERO);
+	assert!(Decimal::new(-16.0).principal_root(2).is_nan());
+	assert!(Decimal::new(16.0).principal_root(0).is_nan());
+
+	for value in [2.0, 1234.5, 1e-7, 3e15] {
+		for exponent in [0.0, 1.0, 2.0, 101.0, -55.0] {
+			let decimal = Decimal::new(value) * super::from_mantissa_exponent(1.0, exponent);
+			assert!(decimal.principal_root(2).eq_tolerance(&decimal.sqrt(), &tolerance));
+			assert!(decimal.principal_root(3).eq_tolerance(&decimal.cbrt(), &tolerance));
+			assert!((-decimal)
+				.principal_root(3)
+				.eq_tolerance(&(-decimal).cbrt(), &tolerance));
+		}
+	}
+}
+
+#[test]
+fn is_monotonic() {
+	use super::{is_monotonic_decreasing, is_monotonic_increasing};
+
+	let increasing = [
+		Decimal::new(1.0),
+		Decimal::new(1e5),
+		Decimal::new(3e10),
+		Decimal::MAX_VALUE,
+	];
+	let dip = [
+		Decimal::new(1.0),
+		Decimal::new(1e5),
+		Decimal::new(9e4),
+		Decimal::new(1e6),
+	];
+	let equal = [
+		Decimal::new(-2.0),
+		Decimal::new(7.0),
+		Decimal::new(7.0),
+		Decimal::new(8.0),
+	];
+
+	assert!(is_monotonic_increasing(&increasing));
+	assert!(!is_monotonic_increasing(&dip));
+	assert!(is_monotonic_increasing(&equal));
+	assert!(is_monotonic_increasing(&[]));
+	assert!(!is_monotonic_increasing(&[Decimal::new(1.0), Decimal::NAN]));
+
+	let reversed: Vec<Decimal> = equal.iter().rev().copied().collect();
+	assert!(is_monotonic_decreasing(&reversed));
+	assert!(!is_monotonic_decreasing(&increasing));
+	assert!(!is_monotonic_decreasing(&dip));
+	assert!(!is_monotonic_decreasing(&[Decimal::NAN]));
+}
+
+#[test]
+fn product_clamped() {
+	let cap = Decimal::new(1e6);
+	let values = [Decimal::new(1e3), Decimal::new(1e5), Decimal::new(0.1)];
+
+	assert_eq!(super::product_clamped(&values[..2], &cap), cap);
+	assert_eq!(super::product_clamped(&values, &cap), Decimal::new(1e5));
+	assert_eq!((values[0] * values[1] * values[2]).min(&cap), cap);
+	assert_eq!(
+		super::product_clamped(&[Decimal::new(2.0), Decimal::new(3.0)], &cap),
+		Decimal::new(6.0)
+	);
+	assert_eq!(super::product_clamped(&[], &cap), Decimal::ONE);
+
+	let huge = super::from_mantissa_exponent(1.0, super::EXP_LIMIT - 1.0);
+	assert_eq!(super::product_clamped(&[huge, huge, huge], &cap), cap);
+}
+
+#[test]
+fn rem() {
+	assert_eq!(Decimal::new(130.0) % 60.0, Decimal::new(10.0));
+	assert_eq!(Decimal::new(130.0) % Decimal::new(60.0), Decimal::new(10.0));
+	assert_eq!(Decimal::new(-130.0) % 60.0, Decimal::new(-10.0));
+	assert_eq!(Decimal::new(120.0) % 60.0, Decimal::ZERO);
+	assert_eq!(Decimal::new(1e30) % 60.0, Decimal::ZERO);
+	assert!((Decimal::new(1.0) % 0.0).is_nan());
+
+	let mut timer = Decimal::new(7265.0);
+	timer %= 3600.0;
+	assert_eq!(timer, Decimal::new(65.0));
+	timer %= &Decimal::new(60.0);
+	assert_eq!(timer, Decimal::new(5.0));
+}
+
+#[test]
+fn ceil_at_exponent() {
+	assert_eq!(Decimal::new(1234.0).ceil_at_exponent(3), Decimal::new(2000.0));
+	assert_eq!(Decimal::new(1234.0).ceil_at_exponent(0), Decimal::new(1234.0));
+	assert_eq!(Decimal::new(1230.0).ceil_at_exponent(1), Decimal::new(1230.0));
+	assert_eq!(Decimal::new(2000.0).ceil_at_exponent(3), Decimal::new(2000.0));
+	assert_eq!(Decimal::new(12.0).ceil_at_exponent(3), Decimal::new(1000.0));
+	assert_eq!(Decimal::new(-1234.0).ceil_at_exponent(3), Decimal::new(-1000.0));
+	assert_eq!(Decimal::new(-12.0).ceil_at_exponent(3), Decimal::ZERO);
+	assert_eq!(Decimal::ZERO.ceil_at_exponent(3), Decimal::ZERO);
+	assert_eq!(Decimal::new(0.123).ceil_at_exponent(-2), Decimal::new(0.13));
+	assert_eq!(
+		super::from_mantissa_exponent(4.5678, 100.0).ceil_at_exponent(100),
+		super::from_mantissa_exponent(5.0, 100.0)
+	);
+}
+
+#[test]
+fn to_significant() {
+	assert_eq!(Decimal::new(1234.5).to_significant(3), "1230");
+	assert_eq!(Decimal::new(1234.5).to_significant(6), "1234.50");
+	assert_eq!(Decimal::new(9999.0).to_significant(2), "10000");
+	assert_eq!(Decimal::new(0.00012345).to_significant(3), "0.000123");
+	assert_eq!(Decimal::new(-42.0).to_significant(1), "-40");
+	assert_eq!(Decimal::new(1.5e25).to_significant(3), "1.50e+25");
+	assert_eq!(Decimal::new(1.5e-8).to_significant(2), "1.5e-8");
+	assert_eq!(
+		super::from_mantissa_exponent(9.996, 400.0).to_significant(3),
+		"1.00e+401"
+	);
+	assert_eq!(
+		Decimal::new(1.5).to_significant(400),
+		Decimal::new(1.5).to_significant(17)
+	);
+	assert_eq!(Decimal::new(1.5e100).to_significant(400), "1.5000000000000000e+100");
+	assert_eq!(Decimal::ZERO.to_significant(3), "0");
+	assert_eq!(Decimal::NAN.to_significant(3), "NaN");
+}
+
+#[test]
+fn ema() {
+	let mut ema = super::Ema::new(0.2);
+	assert_eq!(ema.get(), Decimal::ZERO);
+
+	ema.update(&Decimal::new(100.0));
+	assert_eq!(ema.get(), Decimal::new(100.0));
+	ema.update(&Decimal::new(200.0));
+	assert!(ema.get().eq_tolerance(&Decimal::new(120.0), &Decimal::new(1e-12)));
+
+	let constant = Decimal::new(5e30);
+	for _ in 0..200 {
+		ema.update(&constant);
+	}
+	assert!(ema.get().eq_tolerance(&constant, &Decimal::new(1e-12)));
+}
+
+#[test]
+fn to_superscript() {
+	assert_eq!(Decimal::new(1.5e40).to_superscript(2), "1.50×10⁴⁰");
+	assert_eq!(Decimal::new(-2.5e-7).to_superscript(1), "-2.5×10⁻⁷");
+	assert_eq!(Decimal::new(9.999e12).to_superscript(2), "1.00×10¹³");
+	assert_eq!(
+		super::from_mantissa_exponent(1.0, 1234567890.0).to_superscript(0),
+		"1×10¹²³⁴⁵⁶⁷⁸⁹⁰"
+	);
+	let long = Decimal::new(1.5).to_superscript(400);
+	assert!(long.starts_with("1.5000") && long.ends_with("×10⁰"));
+	assert_eq!(long.chars().count(), "1.".len() + 400 + "×10⁰".chars().count());
+	assert_eq!(Decimal::ZERO.to_superscript(1), "0.0×10⁰");
+	assert_eq!(Decimal::NAN.to_superscript(2), "NaN");
+	assert_eq!(Decimal::MAX_VALUE.to_superscript(2), "Infinity");
+}
+
+#[test]
+fn purchases_for_multiplier() {
+	use super::purchases_for_multiplier;
+
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::new(2.0), &Decimal::new(1024.0)),
+		Decimal::new(10.0)
+	);
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::new(2.0), &Decimal::new(1025.0)),
+		Decimal::new(11.0)
+	);
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::new(1.1), &Decimal::new(1000.0)),
+		Decimal::new(73.0)
+	);
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::new(1e10), &super::from_mantissa_exponent(1.0, 1000.0)),
+		Decimal::new(100.0)
+	);
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::new(2.0), &Decimal::ONE),
+		Decimal::ZERO
+	);
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::ONE, &Decimal::new(10.0)),
+		Decimal::MAX_VALUE
+	);
+	assert_eq!(
+		purchases_for_multiplier(&Decimal::new(0.5), &Decimal::new(10.0)),
+		Decimal::MAX_VALUE
+	);
+}
+
+#[test]
+fn integer_digit_iter() {
+	let digits = |decimal: Decimal| decimal.integer_digit_iter().collect::<Vec<u8>>();
+
+	assert_eq!(digits(Decimal::new(12345.0)), vec![1, 2, 3, 4, 5]);
+	assert_eq!(digits(Decimal::new(12345.9)), vec![1, 2, 3, 4, 5]);
+	assert_eq!(digits(Decimal::new(-12.5)), vec![1, 3]);
+	assert_eq!(digits(Decimal::ZERO), vec![0]);
+	assert_eq!(digits(Decimal::new(0.7)), vec![0]);
+	assert!(digits(Decimal::NAN).is_empty());
+
+	let mut expected = vec![1, 5];
+	expected.extend([0; 19]);
+	assert_eq!(digits(Decimal::new(1.5e20)), expected);
+
+	let huge = super::from_mantissa_exponent(1.5, 1e12);
+	assert_eq!(huge.integer_digit_iter().take(3).collect::<Vec<u8>>(), vec![1, 5, 0]);
+	assert_eq!(
+		huge.integer_digit_iter().size_hint(),
+		(1e12 as usize + 1, Some(1e12 as usize + 1))
+	);
+	assert_eq!(
+		super::from_mantissa_exponent(1.5, 1e4).integer_digit_iter().count(),
+		10001
+	);
+}
+
+#[test]
+fn to_shortest() {
+	assert_eq!(Decimal::new(0.1).to_shortest(), "0.1");
+	assert_eq!(Decimal::new(100.0).to_shortest(), "100");
+	assert_eq!(Decimal::new(1.5e40).to_shortest(), "1.5e+40");
+	assert_eq!(Decimal::new(-123.456).to_shortest(), "-123.456");
+	assert_eq!(Decimal::new(0.00025).to_shortest(), "0.00025");
+	assert_eq!(Decimal::new(2.5e-9).to_shortest(), "2.5e-9");
+	assert_eq!(Decimal::ZERO.to_shortest(), "0");
+	assert_eq!(Decimal::NAN.to_shortest(), "NaN");
+
+	let tolerance = Decimal::new(1e-15);
+	for mantissa in [1.0, 1.5, 3.3333333333333335, 9.999999999999998, 1.0000000000000002] {
+		for exponent in [-400.0, -7.0, -6.0, -1.0, 0.0, 5.0, 16.0, 20.0, 21.0, 400.0] {
+			let decimal = super::from_mantissa_exponent(mantissa, exponent);
+			let parsed: Decimal = decimal.to_shortest().parse().unwrap();
+			assert!(
+				parsed.eq_tolerance(&decimal, &tolerance),
+				"{:?} was parsed as {:?}",
+				decimal,
+				parsed
+			);
+		}
+	}
+}
+
+#[test]
+fn prestige_gain() {
+	use super::prestige_gain;
+
+	let requirement = Decimal::new(1e6);
+	assert_eq!(prestige_gain(&Decimal::new(5e5), &requirement, 0.5), Decimal::ZERO);
+	assert_eq!(prestige_gain(&Decimal::new(1e6), &requirement, 0.5), Decimal::ONE);
+	assert_eq!(prestige_gain(&Decimal::new(3.9e6), &requirement, 0.5), Decimal::ONE);
+	assert_eq!(prestige_gain(&Decimal::new(4e6), &requirement, 0.5), Decimal::new(2.0));
+	assert_eq!(prestige_gain(&Decimal::new(1e8), &requirement, 0.5), Decimal::new(10.0));
+	assert_eq!(
+		prestige_gain(&Decimal::new(1e12), &requirement, 0.5),
+		Decimal::new(1000.0)
+	);
+	assert_eq!(prestige_gain(&Decimal::new(8e6), &requirement, 1.0), Decimal::new(8.0));
+	assert_eq!(
+		prestige_gain(&super::from_mantissa_exponent(1.0, 206.0), &requirement, 0.5),
+		super::from_mantissa_exponent(1.0, 100.0)
+	);
+}
+
+#[test]
+fn time_to_reach() {
+	use super::time_to_reach;
+
+	let rate = Decimal::new(1000.0);
+	assert_eq!(
+		time_to_reach(&Decimal::ZERO, &Decimal::new(1e6), &rate),
+		Decimal::new(1000.0)
+	);
+	assert_eq!(
+		time_to_reach(&Decimal::new(5e5), &Decimal::new(1e6), &rate),
+		Decimal::new(500.0)
+	);
+	assert_eq!(
+		time_to_reach(&Decimal::new(1e6), &Decimal::new(1e6), &rate),
+		Decimal::ZERO
+	);
+	assert_eq!(
+		time_to_reach(&Decimal::new(2e6), &Decimal::new(1e6), &rate),
+		Decimal::ZERO
+	);
+	assert_eq!(
+		time_to_reach(&Decimal::ZERO, &Decimal::new(1e6), &Decimal::ZERO),
+		Decimal::MAX_VALUE
+	);
+	assert_eq!(
+		time_to_reach(&Decimal::ZERO, &Decimal::new(1e6), &Decimal::new(-1.0)),
+		Decimal::MAX_VALUE
+	);
+	assert_eq!(
+		time_to_reach(&Decimal::ZERO, &super::from_mantissa_exponent(1.0, 500.0), &rate),
+		super::from_mantissa_exponent(1.0, 497.0)
+	);
+}
+
+#[test]
+fn time_to_reach_exponential() {
+	use super::{compound, time_to_reach_exponential};
+
+	let tolerance = Decimal::new(1e-9);
+	let rate = Decimal::new(0.05);
+	let current = Decimal::new(100.0);
+	for target in [
+		Decimal::new(1e3),
+		Decimal::new(1e10),
+		super::from_mantissa_exponent(1.0, 500.0),
+	] {
+		let time = time_to_reach_exponential(&current, &target, &rate);
+		assert!(compound(&current, &rate, &time).eq_tolerance(&target, &tolerance));
+	}
+
+	assert!(
+		time_to_reach_exponential(&Decimal::ONE, &Decimal::new(2.0), &Decimal::ONE)
+			.eq_tolerance(&Decimal::ONE, &tolerance)
+	);
+	assert!(
+		time_to_reach_exponential(&Decimal::ONE, &Decimal::new(std::f64::consts::E), &Decimal::new(1e-20))
+			.eq_tolerance(&Decimal::new(1e20), &tolerance)
+	);
+	assert!(time_to_reach_exponential(
+		&Decimal::ONE,
+		&super::from_mantissa_exponent(1.0, 2000.0),
+		&super::from_mantissa_exponent(1.0, 400.0)
+	)
+	.eq_tolerance(&Decimal::new(5.0), &tolerance));
+	assert_eq!(
+		time_to_reach_exponential(&Decimal::new(1e3), &Decimal::new(1e2), &rate),
+		Decimal::ZERO
+	);
+	assert_eq!(
+		time_to_reach_exponential(&current, &Decimal::new(1e3), &Decimal::ZERO),
+		Decimal::MAX_VALUE
+	);
+	assert_eq!(
+		time_to_reach_exponential(&Decimal::ZERO, &Decimal::new(1e3), &rate),
+		Decimal::MAX_VALUE
+	);
+}
+
+#[test]
+fn canonicalize_precision() {
+	let decimal = super::from_mantissa_exponent(1.2345678901234567, 50.0);
+	let canonical = decimal.canonicalize_precision();
+	assert_eq!(canonical, super::from_mantissa_exponent(1.23456789012346, 50.0));
+	assert_eq!(canonical.canonicalize_precision(), canonical);
+
+	let nearby = super::from_mantissa_exponent(1.234_567_890_123_456, 50.0);
+	assert_ne!(nearby, decimal);
+	assert_eq!(nearby.canonicalize_precision(), canonical);
+
+	assert_eq!(
+		super::from_mantissa_exponent_no_normalize(9.999_999_999_999_998, 3.0).canonicalize_precision(),
+		Decimal::new(1e4)
+	);
+	assert!(Decimal::NAN.canonicalize_precision().is_nan());
+}
+
+#[test]
+#[cfg(feature = "deterministic")]
+fn deterministic() {
+	let sum = Decimal::new(0.1) + Decimal::new(0.2);
+	assert_eq!(sum, sum.canonicalize_precision());
+	assert_eq!(sum.to_string(), "0.3");
+
+	let product = Decimal::new(1.1) * Decimal::new(1.1) * Decimal::new(1.1);
+	assert_eq!(product, product.canonicalize_precision());
+
+	let two = Decimal::new(2.0);
+	for result in [
+		two.sqrt(),
+		two.cbrt(),
+		two.exp(),
+		two.sqr(),
+		two.cube(),
+		Decimal::new(20.0).factorial(),
+	] {
+		assert_eq!(result, result.canonicalize_precision());
+	}
+	assert_eq!(two.sqrt().mantissa, (std::f64::consts::SQRT_2 * 1e14).round() / 1e14);
+}
+
+#[test]
+fn weighted_selection() {
+	use super::{cumulative_weights, select_index};
+
+	let weights = [Decimal::new(1.0), Decimal::new(3.0), Decimal::ZERO, Decimal::new(6.0)];
+	let cumulative = cumulative_weights(&weights);
+	assert_eq!(
+		cumulative,
+		vec![
+			Decimal::new(1.0),
+			Decimal::new(4.0),
+			Decimal::new(4.0),
+			Decimal::new(10.0)
+		]
+	);
+
+	let mut counts = [0; 4];
+	for roll in 0..100 {
+		counts[select_index(&cumulative, &Decimal::new(roll as f64 / 10.0)).unwrap()] += 1;
+	}
+	assert_eq!(counts, [10, 30, 0, 60]);
+	assert_eq!(select_index(&cumulative, &Decimal::new(10.0)), Some(3));
+	assert_eq!(select_index(&[], &Decimal::ONE), None);
+
+	let huge = [
+		super::from_mantissa_exponent(1.0, 100.0),
+		super::from_mantissa_exponent(1.0, 200.0),
+	];
+	let cumulative = cumulative_weights(&huge);
+	assert_eq!(
+		select_index(&cumulative, &super::from_mantissa_exponent(5.0, 99.0)),
+		Some(0)
+	);
+	assert_eq!(
+		select_index(&cumulative, &super::from_mantissa_exponent(5.0, 199.0)),
+		Some(1)
+	);
+}
+
+#[test]
+fn bisect() {
+	let limit = Decimal::new(1e10);
+	let root = super::bisect(
+		&Decimal::ONE,
+		&super::from_mantissa_exponent(1.0, 1000.0),
+		|x| x * x <= limit,
+		64,
+	);
+	assert!(root * root <= limit);
+	assert!(root.eq_tolerance(&Decimal::new(1e5), &Decimal::new(1e-9)));
+
+	let cost = |n: &Decimal| Decimal::new(1.15).pow(n);
+	let budget = super::from_mantissa_exponent(1.0, 50.0);
+	let count = super::bisect(&Decimal::ONE, &Decimal::new(1e6), |n| cost(n) <= budget, 64);
+	assert!(count.eq_tolerance(&Decimal::new(50.0 / 1.15_f64.log10()), &Decimal::new(1e-9)));
+
+	assert_eq!(
+		super::bisect(&Decimal::ONE, &Decimal::new(10.0), |_| true, 64),
+		Decimal::new(10.0)
+	);
+	assert_eq!(
+		super::bisect(&Decimal::ONE, &Decimal::new(10.0), |_| false, 64),
+		Decimal::ONE
+	);
+}
+
+#[test]
+fn sub_with_cancellation() {
+	let a = super::from_mantissa_exponent(1.234567891, 50.0);
+	let b = super::from_mantissa_exponent(1.234567890, 50.0);
+	let (difference, lost) = a.sub_with_cancellation(&b);
+	assert_eq!(difference, a - b);
+	assert!(lost > 9.0 && lost < 9.1);
+
+	assert!(Decimal::new(100.0).sub_with_cancellation(&Decimal::new(1.0)).1 < 0.01);
+	assert_eq!(Decimal::new(100.0).sub_with_cancellation(&Decimal::new(-100.0)).1, 0.0);
+	assert_eq!(
+		Decimal::new(5.0).sub_with_cancellation(&Decimal::ZERO),
+		(Decimal::new(5.0), 0.0)
+	);
+	assert_eq!(a.sub_with_cancellation(&a), (Decimal::ZERO, 17.0));
+}
+
+#[test]
+fn delta_encoding() {
+	use super::{delta_decode, delta_encode};
+
+	let tolerance = Decimal::new(1e-12);
+	let all_close = |a: &[Decimal], b: &[Decimal]| {
+		a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.eq_tolerance(b, &tolerance))
+	};
+
+	let values = [
+		Decimal::new(10.0),
+		Decimal::new(12.0),
+		Decimal::new(15.0),
+		Decimal::new(11.0),
+	];
+	let deltas = delta_encode(&values);
+	assert!(all_close(
+		&deltas,
+		&[
+			Decimal::new(10.0),
+			Decimal::new(2.0),
+			Decimal::new(3.0),
+			Decimal::new(-4.0)
+		]
+	));
+	assert!(all_close(&delta_decode(&deltas), &values));
+
+	let series: Vec<Decimal> = (0..100)
+		.map(|tick| super::from_mantissa_exponent(1.0 + tick as f64 * 0.01, 100.0))
+		.collect();
+	assert!(all_close(&delta_decode(&delta_encode(&series)), &series));
+
+	assert!(delta_encode(&[]).is_empty());
+}
+
+#[test]
+fn relative_error_vs() {
+	let bound = 1e-15;
+
+	assert!((Decimal::new(3.224) * Decimal::new(1.24)).relative_error_vs(3.99776) < bound);
+	assert!((Decimal::new(123456.0) * Decimal::new(0.001)).relative_error_vs(123.456) < bound);
+	assert!((Decimal::new(1e10) * Decimal::new(3e-5)).relative_error_vs(3e5) < bound);
+	assert!((Decimal::new(1.0) / Decimal::new(3.0)).relative_error_vs(1.0 / 3.0) < bound);
+	assert!((Decimal::new(4.0) / Decimal::new(1.6e-3)).relative_error_vs(2500.0) < bound);
+	assert!(Decimal::new(1.01).relative_error_vs(1.0) > 0.0099);
+}
+
+#[test]
+fn from_f64_shifted() {
+	assert_eq!(Decimal::from_f64_shifted(1.5, 40.0), Decimal::new(1.5e40));
+	assert_eq!(Decimal::from_f64_shifted(-250.0, -3.0), Decimal::new(-0.25));
+	assert_eq!(
+		Decimal::from_f64_shifted(1.5, 1000.0),
+		super::from_mantissa_exponent(1.5, 1000.0)
+	);
+	assert!(
+		Decimal::from_f64_shifted(2.0, 0.5).eq_tolerance(&Decimal::new(2.0 * 10.0_f64.sqrt()), &Decimal::new(1e-12))
+	);
+	assert_eq!(Decimal::from_f64_shifted(0.0, 40.0), Decimal::ZERO);
+	assert_eq!(
+		Decimal::from_f64_shifted(f64::INFINITY, 40.0),
+		Decimal::new(f64::INFINITY)
+	);
+	assert!(Decimal::from_f64_shifted(f64::NAN, 40.0).is_nan());
+}
+
+#[test]
+fn max_below() {
+	let values = [
+		Decimal::new(5.0),
+		super::from_mantissa_exponent(1.0, 300.0),
+		Decimal::NAN,
+		Decimal::new(9.5e9),
+		Decimal::new(-1e20),
+		Decimal::new(1e10),
+	];
+	let cap = Decimal::new(1e10);
+
+	assert_eq!(super::max_below(&values, &cap), Some(Decimal::new(9.5e9)));
+	assert_eq!(super::max_below(&values, &Decimal::ZERO), Some(Decimal::new(-1e20)));
+	assert_eq!(super::max_below(&values, &Decimal::new(-1e30)), None);
+	assert_eq!(super::max_below(&[], &cap), None);
+}
+
+#[test]
+fn piecewise_eval() {
+	let breakpoints = [
+		(Decimal::new(100.0), Decimal::new(3.0)),
+		(Decimal::new(10.0), Decimal::new(1.0)),
+		(Decimal::new(20.0), Decimal::new(2.0)),
+	];
+	let tolerance = Decimal::new(1e-12);
+
+	assert!(Decimal::new(15.0)
+		.piecewise_eval(&breakpoints)
+		.eq_tolerance(&Decimal::new(1.5), &tolerance));
+	assert!(Decimal::new(60.0)
+		.piecewise_eval(&breakpoints)
+		.eq_tolerance(&Decimal::new(2.5), &tolerance));
+	assert_eq!(Decimal::new(20.0).piecewise_eval(&breakpoints), Decimal::new(2.0));
+	assert_eq!(Decimal::new(5.0).piecewise_eval(&breakpoints), Decimal::new(1.0));
+	assert_eq!(Decimal::new(1e50).piecewise_eval(&breakpoints), Decimal::new(3.0));
+	assert_eq!(Decimal::new(5.0).piecewise_eval(&breakpoints[..1]), Decimal::new(3.0));
+	assert!(Decimal::new(5.0).piecewise_eval(&[]).is_nan());
+}
+
+#[test]
+fn sum_stable() {
+	let mut values = vec![Decimal::new(1e18)];
+	values.extend(vec![Decimal::ONE; 100_000]);
+
+	assert_eq!(values.iter().sum::<Decimal>(), Decimal::new(1e18));
+	let sum = super::sum_stable(&values);
+	// Only the leading digit or so of the contribution fits in the mantissa next to 1e18.
+	assert!((sum - Decimal::new(1e18)).eq_tolerance(&Decimal::new(1e5), &Decimal::new(0.2)));
+
+	assert_eq!(
+		super::sum_stable(&[Decimal::new(-5.0), Decimal::new(2.0), Decimal::new(10.0)]),
+		Decimal::new(7.0)
+	);
+	assert_eq!(super::sum_stable(&[]), Decimal::ZERO);
+}
+
+#[test]
+fn abbreviation_tier() {
+	assert_eq!(Decimal::new(999.0).abbreviation_tier(), 0);
+	assert_eq!(Decimal::new(1.5e3).abbreviation_tier(), 1);
+	assert_eq!(Decimal::new(999_999.0).abbreviation_tier(), 1);
+	assert_eq!(Decimal::new(1e6).abbreviation_tier(), 2);
+	assert_eq!(Decimal::new(-2.5e10).abbreviation_tier(), 3);
+	assert_eq!(Decimal::new(0.001).abbreviation_tier(), 0);
+	assert_eq!(Decimal::ZERO.abbreviation_tier(), 0);
+	assert_eq!(super::from_mantissa_exponent(1.0, 3003.0).abbreviation_tier(), 1001);
+	assert_eq!(
+		Decimal::MAX_VALUE.abbreviation_tier(),
+		(super::EXP_LIMIT / 3.0).min(i32::MAX as f64) as i32
+	);
+}
+
+#[test]
+fn window_max() {
+	let values: Vec<Decimal> = [3.0, 1e10, -2.0, 5.0, 5.0, 4.0, 1e-3, 7e8, 2.0, 6.0]
+		.iter()
+		.map(|&value| Decimal::new(value))
+		.collect();
+
+	for window in 1..=values.len() {
+		let expected: Vec<Decimal> = values
+			.windows(window)
+			.map(|window| window.iter().fold(window[0], |max, value| max.max(value)))
+			.collect();
+		assert_eq!(super::window_max(&values, window), expected);
+	}
+
+	assert!(super::window_max(&values, 0).is_empty());
+	assert!(super::window_max(&values, values.len() + 1).is_empty());
+	assert!(super::window_max(&[Decimal::ONE, Decimal::NAN], 2)[0].is_nan());
+}
+
+#[test]
+fn trailing_zeros() {
+	assert_eq!(Decimal::new(1e20).trailing_zeros(), 20);
+	assert_eq!(Decimal::new(1.2e20).trailing_zeros(), 19);
+	assert_eq!(Decimal::new(123.0).trailing_zeros(), 0);
+	assert_eq!(Decimal::new(-1230.0).trailing_zeros(), 1);
+	assert_eq!(Decimal::new(12.5).trailing_zeros(), 0);
+	assert_eq!(Decimal::new(0.01).trailing_zeros(), 0);
+	assert_eq!(Decimal::ZERO.trailing_zeros(), 0);
+	assert_eq!(super::from_mantissa_exponent(4.5, 1e9).trailing_zeros(), 999_999_999);
+}
+
+#[test]
+fn mod_pow10() {
+	assert_eq!(Decimal::new(12345.0).mod_pow10(3), Decimal::new(345.0));
+	assert_eq!(Decimal::new(12345.0).mod_pow10(0), Decimal::ZERO);
+	assert_eq!(Decimal::new(-12345.0).mod_pow10(2), Decimal::new(-45.0));
+	assert_eq!(Decimal::new(12.0).mod_pow10(3), Decimal::new(12.0));
+	assert_eq!(Decimal::new(1.2345e20).mod_pow10(3), Decimal::ZERO);
+	assert_eq!(Decimal::new(1.5e30).mod_pow10(10), Decimal::ZERO);
+	assert!(Decimal::new(2.75)
+		.mod_pow10(0)
+		.eq_tolerance(&Decimal::new(0.75), &Decimal::new(1e-12)));
+	assert!(super::from_mantissa_exponent(1.25, 320.0)
+		.mod_pow10(319)
+		.eq_tolerance(&super::from_mantissa_exponent(5.0, 318.0), &Decimal::new(1e-12)));
+	assert!(super::from_mantissa_exponent(1.25, -320.0)
+		.mod_pow10(-321)
+		.eq_tolerance(&super::from_mantissa_exponent(5.0, -322.0), &Decimal::new(1e-12)));
+	assert_eq!(
+		super::from_mantissa_exponent(1.0, -320.0).mod_pow10(-330),
+		Decimal::ZERO
+	);
+	assert!(Decimal::NAN.mod_pow10(3).is_nan());
+}
+
+#[test]
+fn hash_to_unit() {
+	let decimal = super::from_mantissa_exponent(1.5, 1000.0);
+	assert_eq!(decimal.hash_to_unit(), decimal.hash_to_unit());
+	assert_eq!(
+		decimal.hash_to_unit(),
+		super::from_mantissa_exponent(1.5, 1000.0).hash_to_unit()
+	);
+	assert_eq!(Decimal::ZERO.hash_to_unit(), (-Decimal::ZERO).hash_to_unit());
+
+	let mut buckets = [0; 10];
+	for i in 0..1000 {
+		let value = Decimal::new(i as f64).hash_to_unit();
+		assert!((0.0..1.0).contains(&value));
+		buckets[(value * 10.0) as usize] += 1;
+	}
+	for count in buckets {
+		assert!((60..=140).contains(&count), "{:?}", buckets);
+	}
+
+	assert_ne!(
+		Decimal::new(1.0).hash_to_unit(),
+		super::from_mantissa_exponent_no_normalize(1.0000000000000002, 0.0).hash_to_unit()
+	);
+}
+
+#[test]
+fn decade_fraction() {
+	for exponent in [-50.0, 0.0, 7.0, 1000.0] {
+		let power = super::from_mantissa_exponent(1.0, exponent);
+		assert_eq!(power.decade_fraction(), 0.0);
+		let root = super::from_mantissa_exponent(10.0_f64.sqrt(), exponent);
+		assert!((root.decade_fraction() - 0.5).abs() < 1e-12);
+	}
+
+	assert!((Decimal::new(5e7).decade_fraction() - 0.69897).abs() < 1e-5);
+	assert_eq!(
+		Decimal::new(-5e7).decade_fraction(),
+		Decimal::new(5e7).decade_fraction()
+	);
+	assert_eq!(Decimal::ZERO.decade_fraction(), 0.0);
+	assert!(Decimal::new(9.99).decade_fraction() < 1.0);
+}
+
+#[test]
+fn inverse_variance_combine() {
+	let tolerance = Decimal::new(1e-12);
+	let (a, b) = (Decimal::new(10.0), Decimal::new(20.0));
+
+	// (10/1 + 20/4) / (1/1 + 1/4) = 15 / 1.25 = 12
+	assert!(
+		Decimal::inverse_variance_combine(&a, &Decimal::new(1.0), &b, &Decimal::new(4.0))
+			.eq_tolerance(&Decimal::new(12.0), &tolerance)
+	);
+	assert!(
+		Decimal::inverse_variance_combine(&a, &Decimal::new(3.0), &b, &Decimal::new(3.0))
+			.eq_tolerance(&Decimal::new(15.0), &tolerance)
+	);
+
+	let (a, b) = (
+		super::from_mantissa_exponent(1.0, 100.0),
+		super::from_mantissa_exponent(3.0, 100.0),
+	);
+	let variance = super::from_mantissa_exponent(1.0, 150.0);
+	assert!(Decimal::inverse_variance_combine(&a, &variance, &b, &variance)
+		.eq_tolerance(&super::from_mantissa_exponent(2.0, 100.0), &tolerance));
+
+	assert_eq!(
+		Decimal::inverse_variance_combine(&a, &Decimal::ZERO, &b, &Decimal::ONE),
+		a
+	);
+	assert_eq!(
+		Decimal::inverse_variance_combine(&a, &Decimal::ONE, &b, &Decimal::ZERO),
+		b
+	);
+	assert!(
+		Decimal::inverse_variance_combine(&a, &Decimal::ZERO, &b, &Decimal::ZERO)
+			.eq_tolerance(&super::from_mantissa_exponent(2.0, 100.0), &tolerance)
+	);
+}
+
+#[test]
+fn common_scale() {
+	let values = [Decimal::new(1e200), Decimal::new(2e250), Decimal::new(5e180)];
+	assert!(values.iter().map(Decimal::to_number).product::<f64>().is_infinite());
+
+	let offset = super::common_scale(&values);
+	assert_eq!(offset, 210.0);
+	let scaled: f64 = values
+		.iter()
+		.map(|value| (value * Decimal::pow10(-offset)).to_number())
+		.product();
+	assert!(scaled.is_finite());
+
+	let product = Decimal::new(scaled) * Decimal::pow10(offset * values.len() as f64);
+	let expected = values.iter().fold(Decimal::ONE, |product, value| product * value);
+	assert!(product.eq_tolerance(&expected, &Decimal::new(1e-12)));
+
+	assert_eq!(super::common_scale(&[]), 0.0);
+	assert_eq!(super::common_scale(&[Decimal::ZERO, Decimal::new(-1e10)]), 10.0);
+}
+
+#[test]
+fn to_bar() {
+	let max = super::from_mantissa_exponent(1.0, 500.0);
+
+	assert_eq!(super::from_mantissa_exponent(5.0, 499.0).to_bar(&max, 10), "#####");
+	assert_eq!(max.to_bar(&max, 10), "##########");
+	assert_eq!((max * Decimal::new(1e100)).to_bar(&max, 10), "##########");
+	assert_eq!(Decimal::new(1.0).to_bar(&max, 10), "");
+	assert_eq!(Decimal::new(-5.0).to_bar(&Decimal::new(10.0), 10), "");
+	assert_eq!(Decimal::new(1.0).to_bar(&Decimal::new(3.0), 8), "###");
+	assert_eq!(Decimal::new(5.0).to_bar(&Decimal::ZERO, 10), "");
+}
+
+#[test]
+fn effective_sig_figs() {
+	let reference = Decimal::new(1.0) / Decimal::new(3.0);
+	let single = Decimal::new((1.0_f32 / 3.0_f32) as f64);
+	let figures = single.effective_sig_figs(&reference);
+	assert!((7..=8).contains(&figures), "{}", figures);
+
+	assert_eq!(Decimal::new(1.234).effective_sig_figs(&Decimal::new(1.2341)), 4);
+	assert_eq!(Decimal::new(2.0).effective_sig_figs(&Decimal::new(3.0)), 0);
+	assert_eq!(reference.effective_sig_figs(&reference), super::MAX_SIGNIFICANT_DIGITS);
+	assert_eq!(Decimal::ONE.effective_sig_figs(&Decimal::ZERO), 0);
+}
+
+#[test]
+fn rank_in() {
+	let leaderboard = [
+		super::from_mantissa_exponent(1.0, 500.0),
+		Decimal::new(1e20),
+		Decimal::new(5e3),
+		Decimal::new(5e3),
+		Decimal::new(10.0),
+		Decimal::new(-1.0),
+	];
+
+	for value in [1e30, 1e20, 6e3, 5e3, 4e3, 10.0, 0.0, -1.0, -5.0] {
+		let value = Decimal::new(value);
+		let count = leaderboard.iter().filter(|&element| element > &value).count();
+		assert_eq!(super::rank_in(&leaderboard, &value), count);
+	}
+
+	assert_eq!(super::rank_in(&leaderboard, &Decimal::new(5e3)), 2);
+	assert_eq!(super::rank_in(&[], &Decimal::ONE), 0);
+}
+
+#[test]
+fn hybrid_cost() {
+	use super::{hybrid_cost, sum_hybrid_cost};
+
+	let (base, additive) = (Decimal::new(10.0), Decimal::new(3.0));
+	assert_eq!(
+		hybrid_cost(&base, &additive, &Decimal::new(2.0), &Decimal::new(3.0)),
+		Decimal::new(152.0)
+	);
+
+	let tolerance = Decimal::new(1e-9);
+	for ratio in [1.0, 1.15, 2.0, 0.5] {
+		let ratio = Decimal::new(ratio);
+		let mut total = Decimal::ZERO;
+		for n in 0..20 {
+			let n = Decimal::new(n as f64);
+			assert!(
+				sum_hybrid_cost(&base, &additive, &ratio, &n).eq_tolerance(&total, &tolerance),
+				"{:?} {:?}",
+				ratio,
+				n
+			);
+			total += hybrid_cost(&base, &additive, &ratio, &n);
+		}
+	}
+
+	let zero = Decimal::ZERO;
+	assert_eq!(sum_hybrid_cost(&base, &additive, &zero, &Decimal::ZERO), Decimal::ZERO);
+	assert_eq!(sum_hybrid_cost(&base, &additive, &zero, &Decimal::ONE), base);
+	assert_eq!(sum_hybrid_cost(&base, &additive, &zero, &Decimal::new(5.0)), base);
+}
+
+#[test]
+fn displays_same_as() {
+	assert!(Decimal::new(123.454).displays_same_as(&Decimal::new(123.451), 2));
+	assert!(!Decimal::new(123.456).displays_same_as(&Decimal::new(123.451), 2));
+	assert!(Decimal::new(9.996).displays_same_as(&Decimal::new(10.0), 2));
+	assert!(Decimal::new(1e-5).displays_same_as(&Decimal::new(1e-8), 2));
+	assert!(!Decimal::new(1.0).displays_same_as(&Decimal::new(0.001), 2));
+	assert!(!Decimal::new(1.0).displays_same_as(&Decimal::new(-1.0), 0));
+	assert!(Decimal::new(1e30).displays_same_as(&Decimal::new(1e30), 0));
+	assert!(!Decimal::new(1e30).displays_same_as(&Decimal::new(1e31), 0));
+	assert!(Decimal::NAN.displays_same_as(&Decimal::NAN, 2));
+}
+
+#[test]
+fn round_clean_mantissa() {
+	assert_eq!(Decimal::new(3.7e5).round_clean_mantissa(0.5), Decimal::new(3.5e5));
+	assert_eq!(Decimal::new(3.8e5).round_clean_mantissa(0.5), Decimal::new(4e5));
+	assert_eq!(Decimal::new(9.8e5).round_clean_mantissa(0.5), Decimal::new(1e6));
+	assert_eq!(Decimal::new(-2.3e-4).round_clean_mantissa(0.25), Decimal::new(-2.25e-4));
+	assert_eq!(
+		super::from_mantissa_exponent(6.2, 1000.0).round_clean_mantissa(1.0),
+		super::from_mantissa_exponent(6.0, 1000.0)
+	);
+	assert_eq!(Decimal::new(3.7e5).round_clean_mantissa(0.0), Decimal::new(3.7e5));
+}
+
+#[test]
+fn surprisal() {
+	assert_eq!(Decimal::new(0.5).surprisal(), 1.0);
+	assert!((Decimal::new(0.125).surprisal() - 3.0).abs() < 1e-12);
+	assert_eq!(Decimal::ONE.surprisal(), 0.0);
+	assert!(Decimal::new(2.0).surprisal() < 0.0);
+	assert_eq!(Decimal::ZERO.surprisal(), f64::INFINITY);
+	assert_eq!(Decimal::new(-0.5).surprisal(), f64::INFINITY);
+
+	let tiny = super::from_mantissa_exponent(1.0, -400.0);
+	assert!((tiny.surprisal() - 400.0 * std::f64::consts::LOG2_10).abs() < 1e-9);
+}
+
+#[test]
+fn to_illion_name() {
+	assert_eq!(Decimal::new(1e9).to_illion_name(2), "1.00 billion");
+	assert_eq!(Decimal::new(1e63).to_illion_name(2), "1.00 vigintillion");
+	assert_eq!(Decimal::new(1.5e10).to_illion_name(2), "15.00 billion");
+	assert_eq!(Decimal::new(-2.5e6).to_illion_name(1), "-2.5 million");
+	assert_eq!(Decimal::new(1234.0).to_illion_name(0), "1 thousand");
+	assert_eq!(Decimal::new(999_999_999.0).to_illion_name(2), "1.00 billion");
+	assert_eq!(Decimal::new(999.999).to_illion_name(2), "1.00 thousand");
+	assert_eq!(Decimal::new(999.0).to_illion_name(2), "999.00");
+	assert_eq!(Decimal::new(0.5).to_illion_name(1), "0.5");
+	assert_eq!(Decimal::new(1e66).to_illion_name(2), "1.00e+66");
+	assert_eq!(
+		super::from_mantissa_exponent(1.0, 1e10).to_illion_name(2),
+		super::from_mantissa_exponent(1.0, 1e10).to_exponential(2)
+	);
+	assert_eq!(Decimal::NAN.to_illion_name(2), "NaN");
+}
+
+#[test]
+fn geometric_mean() {
+	let tolerance = Decimal::new(1e-12);
+	let mut mean = super::GeometricMean::new();
+	assert_eq!(mean.get(), Decimal::ZERO);
+
+	let constant = super::from_mantissa_exponent(3.0, 1000.0);
+	for _ in 0..100 {
+		mean.update(&constant);
+	}
+	assert!(mean.get().eq_tolerance(&constant, &tolerance));
+
+	let mut mean = super::GeometricMean::new();
+	mean.update(&Decimal::new(2.0));
+	mean.update(&Decimal::new(8.0));
+	mean.update(&Decimal::ZERO);
+	mean.update(&Decimal::new(-5.0));
+	assert!(mean.get().eq_tolerance(&Decimal::new(4.0), &tolerance));
+}
+
+#[test]
+fn fractional_exponent() {
+	assert!((Decimal::new(5e7).fractional_exponent() - 7.69897).abs() < 1e-5);
+	assert_eq!(Decimal::new(5e7).exponent, 7.0);
+	assert_eq!(Decimal::new(1e7).fractional_exponent(), 7.0);
+	assert_eq!(
+		Decimal::new(-5e7).fractional_exponent(),
+		Decimal::new(5e7).fractional_exponent()
+	);
+	assert_eq!(Decimal::new(5e7).fractional_exponent(), Decimal::new(5e7).log10());
+	assert!((super::from_mantissa_exponent(2.0, -300.0).fractional_exponent() + 299.69897).abs() < 1e-5);
+}
+
+#[test]
+fn from_base2() {
+	assert_eq!(Decimal::from_base2(1.0, 10), Decimal::new(1024.0));
+	assert_eq!(Decimal::from_base2(-1.5, -2), Decimal::new(-0.375));
+	assert_eq!(Decimal::from_base2(0.0, 5000), Decimal::ZERO);
+	assert!(Decimal::from_base2(f64::NAN, 1).is_nan());
+
+	assert!(2.0_f64.powi(5000).is_infinite());
+	let tolerance = Decimal::new(1e-12);
+	let large = Decimal::from_base2(1.0, 5000);
+	assert!(large.eq_tolerance(&Decimal::new(2.0).pow(&Decimal::new(5000.0)), &tolerance));
+	assert_eq!(large.exponent, 1505.0);
+
+	let small = Decimal::from_base2(3.0, -5000);
+	assert!(small.eq_tolerance(
+		&(Decimal::new(3.0) / Decimal::new(2.0).pow(&Decimal::new(5000.0))),
+		&tolerance
+	));
+}
+
+#[test]
+fn z_score() {
+	let (mean, std_dev) = (Decimal::new(100.0), Decimal::new(15.0));
+	assert_eq!(Decimal::new(130.0).z_score(&mean, &std_dev), 2.0);
+	assert_eq!(Decimal::new(85.0).z_score(&mean, &std_dev), -1.0);
+	assert_eq!(mean.z_score(&mean, &std_dev), 0.0);
+
+	let (mean, std_dev) = (
+		super::from_mantissa_exponent(5.0, 500.0),
+		super::from_mantissa_exponent(2.0, 499.0),
+	);
+	assert!((super::from_mantissa_exponent(4.0, 500.0).z_score(&mean, &std_dev) + 5.0).abs() < 1e-12);
+
+	assert!(Decimal::new(130.0).z_score(&mean, &Decimal::ZERO).is_nan());
+}
+
+#[test]
+fn to_aligned() {
+	assert_eq!(Decimal::new(1.5e40).to_aligned(5), "1.500e+40");
+	assert_eq!(Decimal::new(-1.5e40).to_aligned(5), "-1.50e+40");
+	assert_eq!(Decimal::new(9.9999).to_aligned(4), "1.00e+1");
+	assert_eq!(Decimal::new(2.7e-7).to_aligned(2), "3 e-7");
+	assert_eq!(Decimal::ZERO.to_aligned(4), "0.00e+0");
+	assert_eq!(Decimal::NAN.to_aligned(5), "  NaN");
+	assert_eq!(Decimal::new(-1.0).to_aligned(1), "-1e+0");
+	assert_eq!(Decimal::new(-2.7e-7).to_aligned(2), "-3e-7");
+	assert_eq!(Decimal::new(1.0).to_aligned(0), "1e+0");
+
+	for width in 0..10 {
+		for value in [1.0, -1.0, 1234.5678, -9.87654e-12, 9.9999999e99, 5e300] {
+			let aligned = Decimal::new(value).to_aligned(width);
+			let mantissa = aligned.split('e').next().unwrap();
+			let min_width = if value < 0.0 { 2 } else { 1 };
+			assert_eq!(mantissa.chars().count(), width.max(min_width), "{}", aligned);
+			assert!(
+				mantissa
+					.trim_start_matches('-')
+					.starts_with(|c: char| c.is_ascii_digit()),
+				"{}",
+				aligned
+			);
+		}
+	}
+}
+
+#[test]
+fn rate_between() {
+	use super::rate_between;
+
+	let earlier = Decimal::new(100.0);
+	let later = Decimal::new(400.0);
+	assert_eq!(rate_between(&earlier, &later, 3.0), Decimal::new(100.0));
+	assert_eq!(rate_between(&later, &earlier, 0.5), Decimal::new(-600.0));
+	assert_eq!(rate_between(&earlier, &earlier, 2.0), Decimal::ZERO);
+	assert!(rate_between(&earlier, &later, 0.0).is_nan());
+
+	let huge = rate_between(&Decimal::ZERO, &"1e1000".parse::<Decimal>().unwrap(), 10.0);
+	assert_eq!(huge, "1e999".parse::<Decimal>().unwrap());
+}
+
+#[test]
+fn to_synced() {
+	let min = Decimal::ZERO;
+	let max = Decimal::new(1e10);
+	assert_eq!(
+		Decimal::new(1.23456789e5).to_synced(&min, &max, 3),
+		Decimal::new(1.23e5)
+	);
+	assert_eq!(Decimal::new(9.996e3).to_synced(&min, &max, 3), Decimal::new(1e4));
+	assert_eq!(Decimal::new(-5.0).to_synced(&min, &max, 3), min);
+	assert_eq!(Decimal::new(1.23456789e20).to_synced(&min, &max, 3), max);
+	assert_eq!(
+		Decimal::new(1.23456789e5).to_synced(&min, &max, 20),
+		Decimal::new(1.23456789e5)
+	);
+
+	for value in [0.5, 12.345678, 98765.4321, 3.3333333e9, 7e12] {
+		let synced = Decimal::new(value).to_synced(&min, &max, 4);
+		assert!(synced >= min && synced <= max);
+		assert_eq!(synced.to_significant(4).parse::<Decimal>().unwrap(), synced);
+	}
+}
+
+#[test]
+fn accumulate_geometric() {
+	use super::accumulate_geometric;
+
+	let rate = Decimal::new(5.0);
+	for growth in [0.5, 1.0, 1.1, 2.0, 10.0] {
+		let growth = Decimal::new(growth);
+		let mut total = Decimal::ZERO;
+		let mut income = rate;
+		for ticks in 0..20 {
+			let accumulated = accumulate_geometric(&rate, &growth, &Decimal::new(ticks as f64));
+			assert!(
+				accumulated.eq_tolerance(&total, &Decimal::new(1e-12)),
+				"{} {}",
+				accumulated,
+				total
+			);
+			total += income;
+			income *= growth;
+		}
+	}
+
+	assert_eq!(
+		accumulate_geometric(&rate, &Decimal::ONE, &Decimal::new(1e6)),
+		Decimal::new(5e6)
+	);
+	assert!(
+		accumulate_geometric(&Decimal::ONE, &Decimal::new(10.0), &Decimal::new(1000.0)).eq_tolerance(
+			&"1.1111111111111111e999".parse::<Decimal>().unwrap(),
+			&Decimal::new(1e-12)
+		)
+	);
+}
+
+#[test]
+fn would_be_nan() {
+	let negative = Decimal::new(-8.0);
+	let positive = Decimal::new(8.0);
+	let half = Decimal::new(0.5);
+	let three = Decimal::new(3.0);
+
+	assert!(negative.would_be_nan_pow(&half));
+	assert!(negative.pow(&half).is_nan());
+	assert!(!negative.would_be_nan_pow(&three));
+	assert!(!negative.pow(&three).is_nan());
+	assert!(!positive.would_be_nan_pow(&half));
+	assert!(Decimal::NAN.would_be_nan_pow(&three));
+	assert!(positive.would_be_nan_pow(&Decimal::NAN));
+
+	assert!(negative.would_be_nan_sqrt());
+	assert!(negative.sqrt().is_nan());
+	assert!(!positive.would_be_nan_sqrt());
+	assert!(!Decimal::ZERO.would_be_nan_sqrt());
+
+	assert!(negative.would_be_nan_log());
+	assert!(negative.ln().is_nan());
+	assert!(negative.log10().is_nan());
+	assert!(Decimal::ZERO.would_be_nan_log());
+	assert!(!positive.would_be_nan_log());
+	assert!(!positive.ln().is_nan());
+}
+
+#[test]
+fn smoothstep() {
+	let edge0 = Decimal::new(1e100);
+	let edge1 = Decimal::new(3e100);
+	assert_eq!(edge0.smoothstep(&edge0, &edge1), 0.0);
+	assert_eq!(edge1.smoothstep(&edge0, &edge1), 1.0);
+	assert!((Decimal::new(2e100).smoothstep(&edge0, &edge1) - 0.5).abs() < 1e-12);
+	assert_eq!(Decimal::ZERO.smoothstep(&edge0, &edge1), 0.0);
+	assert_eq!(Decimal::new(1e200).smoothstep(&edge0, &edge1), 1.0);
+
+	let quarter = Decimal::new(1.5e100).smoothstep(&edge0, &edge1);
+	assert!((quarter - 0.15625).abs() < 1e-12);
+
+	assert_eq!(Decimal::ONE.smoothstep(&edge0, &edge0), 0.0);
+	assert_eq!(edge0.smoothstep(&edge0, &edge0), 1.0);
+}
+
+#[test]
+fn tier_delta() {
+	let reference = Decimal::new(1000.0);
+	let ratio = Decimal::new(10.0);
+	assert_eq!(Decimal::new(1e5).tier_delta(&reference, &ratio), 2);
+	assert_eq!(Decimal::new(5e5).tier_delta(&reference, &ratio), 2);
+	assert_eq!(Decimal::new(100.0).tier_delta(&reference, &ratio), -1);
+	assert_eq!(Decimal::new(500.0).tier_delta(&reference, &ratio), -1);
+	assert_eq!(reference.tier_delta(&reference, &ratio), 0);
+	assert_eq!(Decimal::new(27.0).tier_delta(&Decimal::ONE, &Decimal::new(3.0)), 3);
+
+	assert_eq!(Decimal::ZERO.tier_delta(&reference, &ratio), 0);
+	assert_eq!(Decimal::new(-1e5).tier_delta(&reference, &ratio), 0);
+	assert_eq!(Decimal::new(1e5).tier_delta(&reference, &Decimal::ONE), 0);
+	assert_eq!(Decimal::NAN.tier_delta(&reference, &ratio), 0);
+}
+
+#[test]
+fn parallel_combine() {
+	use super::parallel_combine;
+
+	assert!(parallel_combine(&[Decimal::new(3.0), Decimal::new(6.0)])
+		.eq_tolerance(&Decimal::new(2.0), &Decimal::new(1e-12)));
+	assert!(
+		parallel_combine(&[Decimal::new(4e100), Decimal::new(4e100), Decimal::new(2e100)])
+			.eq_tolerance(&Decimal::new(1e100), &Decimal::new(1e-12))
+	);
+	assert_eq!(parallel_combine(&[Decimal::new(5.0)]), Decimal::new(5.0));
+	assert_eq!(parallel_combine(&[Decimal::new(5.0), Decimal::ZERO]), Decimal::ZERO);
+	assert_eq!(parallel_combine(&[]), Decimal::MAX_VALUE);
+}
+
+#[test]
+fn magnitude_hue() {
+	assert_eq!(Decimal::ONE.magnitude_hue(12.0), 0.0);
+	assert_eq!(Decimal::new(1e3).magnitude_hue(12.0), 90.0);
+	assert_eq!(Decimal::new(1e6).magnitude_hue(12.0), 180.0);
+	assert_eq!(Decimal::new(1e12).magnitude_hue(12.0), 0.0);
+	assert_eq!(Decimal::new(1e15).magnitude_hue(12.0), 90.0);
+	assert_eq!(Decimal::new(1e-3).magnitude_hue(12.0), 270.0);
+	assert_eq!("1e1200000000003".parse::<Decimal>().unwrap().magnitude_hue(12.0), 90.0);
+	assert!((Decimal::new(5e3).magnitude_hue(12.0) - (90.0 + 30.0 * 5f32.log10())).abs() < 1e-3);
+
+	for decade in 0..48 {
+		let hue = Decimal::new(10f64.powi(decade)).magnitude_hue(12.0);
+		assert!((0.0..360.0).contains(&hue));
+		assert_eq!(hue, (decade % 12) as f32 * 30.0);
+	}
+
+	assert_eq!(Decimal::ZERO.magnitude_hue(12.0), 0.0);
+	assert_eq!(Decimal::new(-1e3).magnitude_hue(12.0), 0.0);
+	assert_eq!(Decimal::new(1e3).magnitude_hue(0.0), 0.0);
+}
+
+#[test]
+fn round_mantissa_bits() {
+	use super::from_mantissa_exponent;
+
+	assert_eq!(
+		from_mantissa_exponent(1.2345, 50.0).round_mantissa_bits(8),
+		from_mantissa_exponent(1.234375, 50.0)
+	);
+	assert_eq!(
+		from_mantissa_exponent(9.87, -3.0).round_mantissa_bits(8),
+		from_mantissa_exponent(9.875, -3.0)
+	);
+	assert_eq!(
+		from_mantissa_exponent(-9.87, 3.0).round_mantissa_bits(8),
+		from_mantissa_exponent(-9.875, 3.0)
+	);
+	assert_eq!(
+		from_mantissa_exponent(9.99, 7.0).round_mantissa_bits(8),
+		from_mantissa_exponent(1.0, 8.0)
+	);
+	assert_eq!(
+		from_mantissa_exponent(3.7, 0.0).round_mantissa_bits(1),
+		from_mantissa_exponent(4.0, 0.0)
+	);
+
+	let decimal = from_mantissa_exponent(1.2345678901234, 1000.0);
+	assert_eq!(decimal.round_mantissa_bits(24).mantissa, decimal.mantissa as f32 as f64);
+	assert_eq!(decimal.round_mantissa_bits(53), decimal);
+	assert_eq!(Decimal::ZERO.round_mantissa_bits(8), Decimal::ZERO);
+	assert!(Decimal::NAN.round_mantissa_bits(8).is_nan());
+}
+
+#[test]
+fn pow_monotonic() {
+	let base = Decimal::new(1.5);
+	let mut previous = base.pow_monotonic(&Decimal::new(-50.0));
+	for step in -49_999..=50_000 {
+		let power = Decimal::new(step as f64 / 1000.0);
+		let result = base.pow_monotonic(&power);
+		assert!(result >= previous, "{} < {} at {}", result, previous, power);
+		assert!(result.eq_tolerance(&base.pow(&power), &Decimal::new(1e-9)));
+		previous = result;
+	}
+
+	let base = Decimal::new(0.3);
+	let mut previous = base.pow_monotonic(&Decimal::ZERO);
+	for step in 1..=10_000 {
+		let result = base.pow_monotonic(&Decimal::new(step as f64 / 100.0));
+		assert!(result <= previous);
+		previous = result;
+	}
+
+	let tolerance = Decimal::new(1e-12);
+	assert!(Decimal::new(-2.0)
+		.pow_monotonic(&Decimal::new(3.0))
+		.eq_tolerance(&Decimal::new(-8.0), &tolerance));
+	assert!(Decimal::new(-2.0)
+		.pow_monotonic(&Decimal::new(2.0))
+		.eq_tolerance(&Decimal::new(4.0), &tolerance));
+	assert!(Decimal::new(-2.0).pow_monotonic(&Decimal::new(0.5)).is_nan());
+	assert_eq!(Decimal::ZERO.pow_monotonic(&Decimal::new(2.0)), Decimal::ZERO);
+}
+
+#[test]
+fn from_rational() {
+	use super::from_mantissa_exponent;
+
+	let third = Decimal::from_rational(1, 3, 17);
+	assert_eq!(third, from_mantissa_exponent(10.0 / 3.0, -1.0));
+	let error = |decimal: Decimal| (decimal.mantissa - 10.0 / 3.0).abs();
+	assert!(error(third) < error(Decimal::new(1.0 / 3.0)));
+
+	assert_eq!(Decimal::from_rational(1, 7, 5), from_mantissa_exponent(1.4286, -1.0));
+	assert_eq!(Decimal::from_rational(2, 3, 3), from_mantissa_exponent(6.67, -1.0));
+	assert_eq!(Decimal::from_rational(-1, 8, 17), Decimal::new(-0.125));
+	assert_eq!(Decimal::from_rational(1, -8, 17), Decimal::new(-0.125));
+	assert_eq!(Decimal::from_rational(-1000, -8, 17), Decimal::new(125.0));
+	assert_eq!(Decimal::from_rational(999, 1, 2), Decimal::new(1000.0));
+	assert_eq!(
+		Decimal::from_rational(i64::MIN, 1, 17),
+		from_mantissa_exponent(-9.223372036854776, 18.0)
+	);
+	assert_eq!(
+		Decimal::from_rational(1, i64::MAX, 3),
+		from_mantissa_exponent(1.08, -19.0)
+	);
+	assert_eq!(Decimal::from_rational(0, 5, 17), Decimal::ZERO);
+	assert!(Decimal::from_rational(1, 0, 17).is_nan());
+}
+
+#[test]
+fn total_per_second() {
+	use super::total_per_second;
+
+	let per_second = (Decimal::new(5.0), 1.0);
+	let per_minute = (Decimal::new(120.0), 60.0);
+	assert!(total_per_second(&[per_second, per_minute]).eq_tolerance(&Decimal::new(7.0), &Decimal::new(1e-12)));
+	assert!(total_per_second(&[per_minute, (Decimal::new(3.6e100), 3600.0)])
+		.eq_tolerance(&Decimal::new(1e97), &Decimal::new(1e-12)));
+	assert_eq!(total_per_second(&[]), Decimal::ZERO);
+	assert!(total_per_second(&[per_second, (Decimal::ONE, 0.0)]).is_nan());
+}
+
+#[test]
+fn crosses_threshold() {
+	let threshold = Decimal::new(1e6);
+	assert!(Decimal::new(9e5).crosses_threshold(&Decimal::new(1e5), &threshold));
+	assert!(Decimal::new(9e5).crosses_threshold(&Decimal::new(5e5), &threshold));
+	assert!(!Decimal::new(9e5).crosses_threshold(&Decimal::new(9e4), &threshold));
+	assert!(!threshold.crosses_threshold(&Decimal::new(1e5), &threshold));
+	assert!(!Decimal::new(2e6).crosses_threshold(&Decimal::new(1e5), &threshold));
+	assert!(!Decimal::new(9e5).crosses_threshold(&Decimal::new(-1e5), &threshold));
+	assert!(Decimal::ZERO.crosses_threshold(&Decimal::new(1e300), &Decimal::new(1e300)));
+}
+
+#[test]
+fn align_exponents() {
+	use super::from_mantissa_exponent;
+
+	let tolerance = Decimal::new(1e-12);
+	let pairs = [
+		(1.5e10, -2.5e7),
+		(3.0, 4.0e-5),
+		(-7.0e100, 1.0e90),
+		(0.0, 6.0e-20),
+		(9.0e40, 0.0),
+	];
+	for (a, b) in pairs.iter().copied() {
+		let (a, b) = (Decimal::new(a), Decimal::new(b));
+		let (a_mantissa, b_mantissa, exponent) = a.align_exponents(&b);
+		assert_eq!(exponent, (a.abs() + b.abs()).exponent);
+		assert!(from_mantissa_exponent(a_mantissa, exponent).eq_tolerance(&a, &tolerance));
+		assert!(from_mantissa_exponent(b_mantissa, exponent).eq_tolerance(&b, &tolerance));
+		assert!(from_mantissa_exponent(a_mantissa + b_mantissa, exponent).eq_tolerance(&(a + b), &tolerance));
+	}
+
+	assert_eq!(Decimal::new(2e50).align_exponents(&Decimal::new(3.0)), (2.0, 0.0, 50.0));
+	let (a, b, exponent) = Decimal::NAN.align_exponents(&Decimal::ONE);
+	assert!(a.is_nan() && b.is_nan() && exponent.is_nan());
+}
+
+#[test]
+fn nice_axis() {
+	use super::nice_axis;
+
+	let (min, max, step) = nice_axis(&Decimal::new(137.0), &Decimal::new(8900.0), 5);
+	assert_eq!(
+		(min, max, step),
+		(Decimal::ZERO, Decimal::new(10000.0), Decimal::new(2000.0))
+	);
+
+	let (min, max, step) = nice_axis(&Decimal::new(137.0), &Decimal::new(8900.0), 10);
+	assert_eq!(
+		(min, max, step),
+		(Decimal::ZERO, Decimal::new(9000.0), Decimal::new(1000.0))
+	);
+
+	let (min, max, step) = nice_axis(&Decimal::new(-3.2e150), &Decimal::new(4.7e151), 6);
+	assert!(min.eq_tolerance(&Decimal::new(-2e151), &Decimal::new(1e-12)));
+	assert!(max.eq_tolerance(&Decimal::new(6e151), &Decimal::new(1e-12)));
+	assert!(step.eq_tolerance(&Decimal::new(2e151), &Decimal::new(1e-12)));
+
+	let (min, max, step) = nice_axis(&Decimal::new(137.0), &Decimal::new(137.0), 5);
+	assert!(min <= Decimal::new(137.0) && max >= Decimal::new(137.0) && step > Decimal::ZERO);
+	assert_eq!(
+		nice_axis(&Decimal::ZERO, &Decimal::ZERO, 3),
+		(Decimal::new(-1.0), Decimal::ONE, Decimal::ONE)
+	);
+
+	assert_eq!(
+		nice_axis(&Decimal::new(8900.0), &Decimal::new(137.0), 5),
+		nice_axis(&Decimal::new(137.0), &Decimal::new(8900.0), 5)
+	);
+	assert!(nice_axis(&Decimal::NAN, &Decimal::ONE, 5).0.is_nan());
+}
+
+#[test]
+fn pow_branches() {
+	let tolerance = Decimal::new(1e-9);
+
+	// Fast track: integer exponent times power, with the mantissa to the power fitting in an f64.
+	assert_eq!(Decimal::new(2.0).pow(&Decimal::new(10.0)), Decimal::new(1024.0));
+	assert!(Decimal::new(-2.0)
+		.pow(&Decimal::new(3.0))
+		.eq_tolerance(&Decimal::new(-8.0), &tolerance));
+	assert!(Decimal::new(-2.0)
+		.pow(&Decimal::new(4.0))
+		.eq_tolerance(&Decimal::new(16.0), &tolerance));
+	assert!(Decimal::new(-2.0).pow(&Decimal::new(0.5)).is_nan());
+
+	// Residue path: the exponent times the power has a fractional part.
+	assert!(Decimal::new(20.0)
+		.pow(&Decimal::new(0.5))
+		.eq_tolerance(&Decimal::new(20f64.sqrt()), &tolerance));
+	assert!(Decimal::new(1e15)
+		.pow(&Decimal::new(1.5))
+		.eq_tolerance(&Decimal::new(1e15 * 1e15f64.sqrt()), &tolerance));
+	assert!(Decimal::new(-20.0).pow(&Decimal::new(0.5)).is_nan());
+	assert!(Decimal::new(-1e15).pow(&Decimal::new(1.5)).is_nan());
+
+	// Fallback path: the mantissa to the power overflows an f64.
+	let expected = Decimal::pow10(1000.0 * 9.99f64.log10());
+	assert!(Decimal::new(9.99)
+		.pow(&Decimal::new(1000.0))
+		.eq_tolerance(&expected, &tolerance));
+	assert!(Decimal::new(-9.99)
+		.pow(&Decimal::new(1000.0))
+		.eq_tolerance(&expected, &tolerance));
+	let expected = Decimal::pow10(1001.0 * 9.99f64.log10());
+	assert!(Decimal::new(-9.99)
+		.pow(&Decimal::new(1001.0))
+		.eq_tolerance(&-expected, &tolerance));
+	assert!(Decimal::new(-9.99).pow(&Decimal::new(1000.5)).is_nan());
+}